@@ -273,8 +273,8 @@ async fn run_path(args: &Args, options: &rune::Options, path: &Path) -> Result<E
 
     let use_cache = options.bytecode && should_cache_be_used(&path, &bytecode_path)?;
     let maybe_unit = if use_cache {
-        let f = fs::File::open(&bytecode_path)?;
-        match bincode::deserialize_from::<_, Unit>(f) {
+        let bytes = fs::read(&bytecode_path)?;
+        match Unit::from_bytes(&bytes) {
             Ok(unit) => {
                 log::trace!("using cache: {}", bytecode_path.display());
                 Some(Arc::new(unit))
@@ -312,8 +312,7 @@ async fn run_path(args: &Args, options: &rune::Options, path: &Path) -> Result<E
 
             if options.bytecode {
                 log::trace!("serializing cache: {}", bytecode_path.display());
-                let f = fs::File::create(&bytecode_path)?;
-                bincode::serialize_into(f, &unit)?;
+                fs::write(&bytecode_path, unit.to_bytes()?)?;
             }
 
             if !warnings.is_empty() {
@@ -324,7 +323,8 @@ async fn run_path(args: &Args, options: &rune::Options, path: &Path) -> Result<E
         }
     };
 
-    let vm = runestick::Vm::new(context.clone(), unit.clone());
+    let mut vm = runestick::Vm::new(context.clone(), unit.clone());
+    vm.set_timer(tokio::time::delay_for);
 
     if args.dump_native_functions {
         writeln!(out, "# functions")?;
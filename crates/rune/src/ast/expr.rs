@@ -72,12 +72,16 @@ pub enum Expr {
     Group(Box<ast::ExprGroup>),
     /// A binary expression.
     Binary(Box<ast::ExprBinary>),
+    /// A range expression.
+    Range(Box<ast::ExprRange>),
     /// A unary expression.
     Unary(Box<ast::ExprUnary>),
     /// An index set operation.
     Index(Box<ast::ExprIndex>),
     /// A break expression.
     Break(Box<ast::ExprBreak>),
+    /// A continue expression.
+    Continue(Box<ast::ExprContinue>),
     /// A yield expression.
     Yield(Box<ast::ExprYield>),
     /// A block as an expression.
@@ -144,6 +148,7 @@ impl Expr {
             Self::Path(_) => Vec::new(),
             Self::Item(item) => item.take_attributes(),
             Self::Break(expr) => take(&mut expr.attributes),
+            Self::Continue(expr) => take(&mut expr.attributes),
             Self::Yield(expr) => take(&mut expr.attributes),
             Self::Block(expr) => take(&mut expr.attributes),
             Self::Return(expr) => take(&mut expr.attributes),
@@ -158,6 +163,7 @@ impl Expr {
             Self::Lit(expr) => take(&mut expr.attributes),
             Self::Assign(expr) => take(&mut expr.attributes),
             Self::Binary(expr) => take(&mut expr.attributes),
+            Self::Range(expr) => take(&mut expr.attributes),
             Self::Call(expr) => take(&mut expr.attributes),
             Self::FieldAccess(expr) => take(&mut expr.attributes),
             Self::Group(expr) => take(&mut expr.attributes),
@@ -179,6 +185,7 @@ impl Expr {
             Self::Path(_) => &[],
             Self::Item(expr) => expr.attributes(),
             Self::Break(expr) => &expr.attributes,
+            Self::Continue(expr) => &expr.attributes,
             Self::Yield(expr) => &expr.attributes,
             Self::Block(expr) => &expr.attributes,
             Self::Return(expr) => &expr.attributes,
@@ -193,6 +200,7 @@ impl Expr {
             Self::Lit(expr) => &expr.attributes,
             Self::Assign(expr) => &expr.attributes,
             Self::Binary(expr) => &expr.attributes,
+            Self::Range(expr) => &expr.attributes,
             Self::Call(expr) => &expr.attributes,
             Self::FieldAccess(expr) => &expr.attributes,
             Self::Group(expr) => &expr.attributes,
@@ -240,6 +248,20 @@ impl Expr {
         false
     }
 
+    /// Test if the expression is a constant expression.
+    ///
+    /// Constant expressions are made up of literals and binary operations over
+    /// other constant expressions. Paths are never considered constant since
+    /// they might refer to local variables.
+    pub fn is_const(&self) -> bool {
+        match self {
+            Self::Lit(..) => true,
+            Self::Group(expr_group) => expr_group.expr.is_const(),
+            Self::Binary(expr_binary) => expr_binary.is_const(),
+            _ => false,
+        }
+    }
+
     /// Parse an expression without an eager brace.
     ///
     /// This is used to solve a syntax ambiguity when parsing expressions that
@@ -262,7 +284,8 @@ impl Expr {
         let expr = Self::parse_chain(p, expr, callable)?;
 
         let expr = if *eager_binary {
-            Self::parse_binary(p, expr, 0, eager_brace)?
+            let expr = Self::parse_binary(p, expr, 0, eager_brace)?;
+            Self::parse_range(p, expr, 0, eager_brace)?
         } else {
             expr
         };
@@ -342,7 +365,8 @@ impl Expr {
         };
 
         let lhs = Self::parse_chain(p, lhs, callable)?;
-        Ok(Self::parse_binary(p, lhs, 0, EagerBrace(true))?)
+        let lhs = Self::parse_binary(p, lhs, 0, EagerBrace(true))?;
+        Ok(Self::parse_range(p, lhs, 0, EagerBrace(true))?)
     }
 
     /// Parse a basic expression.
@@ -435,6 +459,10 @@ impl Expr {
                 p,
                 take(attributes),
             )?)),
+            K![continue] => Self::Continue(Box::new(ast::ExprContinue::parse_with_meta(
+                p,
+                take(attributes),
+            )?)),
             K![yield] => Self::Yield(Box::new(ast::ExprYield::parse_with_meta(
                 p,
                 take(attributes),
@@ -598,6 +626,13 @@ impl Expr {
                 lookahead_tok = ast::BinOp::from_peeker(p.peeker());
             }
 
+            // NB: ranges are permitted on the right-hand side of a membership
+            // test, like `x in 0..10`.
+            if op == ast::BinOp::In && p.peek::<ast::ExprRangeLimits>()? {
+                rhs = Self::parse_range(p, rhs, op.precedence() + 1, eager_brace)?;
+                lookahead_tok = ast::BinOp::from_peeker(p.peeker());
+            }
+
             lhs = Self::Binary(Box::new(ast::ExprBinary {
                 attributes: Vec::new(),
                 lhs,
@@ -611,6 +646,34 @@ impl Expr {
         Ok(lhs)
     }
 
+    /// Parse a range expression, if the given expression is followed by range
+    /// limits like `..` or `..=`.
+    ///
+    /// The end of the range is parsed as a binary expression where operators
+    /// have at least the given precedence.
+    fn parse_range(
+        p: &mut Parser<'_>,
+        from: Self,
+        min_precedence: usize,
+        eager_brace: EagerBrace,
+    ) -> Result<Self, ParseError> {
+        if !p.peek::<ast::ExprRangeLimits>()? {
+            return Ok(from);
+        }
+
+        let limits = p.parse()?;
+        let to = Self::parse_base(p, &mut vec![], eager_brace)?;
+        let to = Self::parse_chain(p, to, Callable(false))?;
+        let to = Self::parse_binary(p, to, min_precedence, eager_brace)?;
+
+        Ok(Self::Range(Box::new(ast::ExprRange {
+            attributes: Vec::new(),
+            from,
+            limits,
+            to,
+        })))
+    }
+
     /// Internal function to construct a literal expression.
     pub(crate) fn from_lit(lit: ast::Lit) -> Self {
         Self::Lit(Box::new(ast::ExprLit {
@@ -685,6 +748,7 @@ impl Peek for Expr {
             K![let] => true,
             K![if] => true,
             K![break] => true,
+            K![continue] => true,
             K![return] => true,
             K![true] => true,
             K![false] => true,
@@ -4,6 +4,34 @@ use runestick::Span;
 use std::fmt;
 
 /// A binary expression.
+///
+/// ```rust
+/// use rune::{testing, ast};
+///
+/// let expr = testing::roundtrip::<ast::Expr>("0b1010 & 0b0110");
+/// assert!(matches!(expr, ast::Expr::Binary(b) if b.op == ast::BinOp::BitAnd));
+///
+/// let expr = testing::roundtrip::<ast::Expr>("a | b ^ c & d << 2");
+///
+/// let expr = match expr {
+///     ast::Expr::Binary(b) if b.op == ast::BinOp::BitOr => b.rhs,
+///     _ => panic!("expected bitwise or"),
+/// };
+///
+/// let expr = match expr {
+///     ast::Expr::Binary(b) if b.op == ast::BinOp::BitXor => b.rhs,
+///     _ => panic!("expected bitwise xor"),
+/// };
+///
+/// let expr = match expr {
+///     ast::Expr::Binary(b) if b.op == ast::BinOp::BitAnd => b.rhs,
+///     _ => panic!("expected bitwise and"),
+/// };
+///
+/// assert!(matches!(expr, ast::Expr::Binary(b) if b.op == ast::BinOp::Shl));
+///
+/// testing::roundtrip::<ast::Expr>("a >> 1 == b");
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq, ToTokens, Spanned)]
 pub struct ExprBinary {
     /// Attributes associated with the binary expression.
@@ -31,6 +59,16 @@ impl ExprBinary {
             self.t1.span()
         }
     }
+
+    /// Test if the binary expression is a constant expression, in which case
+    /// it might be evaluated at compile time.
+    pub fn is_const(&self) -> bool {
+        if self.op.is_assign() || matches!(self.op, BinOp::Is | BinOp::IsNot) {
+            return false;
+        }
+
+        self.lhs.is_const() && self.rhs.is_const()
+    }
 }
 
 expr_parse!(Binary, ExprBinary, "binary expression");
@@ -48,6 +86,8 @@ pub enum BinOp {
     Mul,
     /// Remainder operator `a % b`.
     Rem,
+    /// Exponentiation operator `a ** b`.
+    Pow,
     /// Equality check `a == b`.
     Eq,
     /// Inequality check `a != b`.
@@ -64,6 +104,8 @@ pub enum BinOp {
     Is,
     /// Negated instance of test `a is not b`.
     IsNot,
+    /// Membership test `a in b`.
+    In,
     /// Lazy and operator `&&`.
     And,
     /// Lazy or operator `||`.
@@ -131,14 +173,15 @@ impl BinOp {
     pub(super) fn precedence(self) -> usize {
         // NB: Rules from: https://doc.rust-lang.org/reference/expressions.html#expression-precedence
         match self {
-            Self::Is | Self::IsNot => 11,
+            Self::Is | Self::IsNot => 12,
+            Self::Pow => 11,
             Self::Mul | Self::Div | Self::Rem => 10,
             Self::Add | Self::Sub => 9,
             Self::Shl | Self::Shr => 8,
             Self::BitAnd => 7,
             Self::BitXor => 6,
             Self::BitOr => 5,
-            Self::Eq | Self::Neq | Self::Lt | Self::Gt | Self::Lte | Self::Gte => 4,
+            Self::Eq | Self::Neq | Self::Lt | Self::Gt | Self::Lte | Self::Gte | Self::In => 4,
             Self::And => 3,
             Self::Or => 2,
             // assign operators
@@ -173,6 +216,7 @@ impl BinOp {
             K![*] => Self::Mul,
             K![/] => Self::Div,
             K![%] => Self::Rem,
+            K![**] => Self::Pow,
             K![==] => Self::Eq,
             K![!=] => Self::Neq,
             K![<] => Self::Lt,
@@ -183,6 +227,7 @@ impl BinOp {
                 K![not] => Self::IsNot,
                 _ => Self::Is,
             },
+            K![in] => Self::In,
             K![&&] => Self::And,
             K![||] => Self::Or,
             K![<<] => Self::Shl,
@@ -224,6 +269,7 @@ impl fmt::Display for BinOp {
             Self::Div => write!(f, "/"),
             Self::Mul => write!(f, "*"),
             Self::Rem => write!(f, "%"),
+            Self::Pow => write!(f, "**"),
             Self::Eq => write!(f, "=="),
             Self::Neq => write!(f, "!="),
             Self::Gt => write!(f, ">"),
@@ -232,6 +278,7 @@ impl fmt::Display for BinOp {
             Self::Lte => write!(f, "<="),
             Self::Is => write!(f, "is"),
             Self::IsNot => write!(f, "is not"),
+            Self::In => write!(f, "in"),
             Self::And => write!(f, "&&"),
             Self::Or => write!(f, "||"),
             Self::Shl => write!(f, "<<"),
@@ -16,6 +16,10 @@ use std::borrow::Cow;
 /// testing::roundtrip::<ast::ExprObject>("Foo {\"foo\": 42}");
 /// testing::roundtrip::<ast::ExprObject>("#{\"foo\": 42}");
 /// testing::roundtrip::<ast::ExprObject>("#{\"foo\": 42,}");
+/// testing::roundtrip::<ast::ExprObject>("#{foo, bar}");
+/// testing::roundtrip::<ast::ExprObject>("Foo {foo, bar: 42}");
+/// testing::roundtrip::<ast::ExprObject>("#{..a, z: 9}");
+/// testing::roundtrip::<ast::ExprObject>("Foo {..foo, bar: 42,}");
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Parse, ToTokens, Spanned)]
 pub struct ExprObject {
@@ -26,7 +30,7 @@ pub struct ExprObject {
     #[rune(meta)]
     pub ident: ObjectIdent,
     /// Assignments in the object.
-    pub assignments: ast::Braced<FieldAssign, T![,]>,
+    pub assignments: ast::Braced<ObjectField, T![,]>,
 }
 
 impl Peek for ExprObject {
@@ -57,6 +61,45 @@ impl Parse for ObjectIdent {
     }
 }
 
+/// An entry in an object expression.
+#[derive(Debug, Clone, PartialEq, Eq, ToTokens, Spanned)]
+pub enum ObjectField {
+    /// An assignment to a field, like `foo: 42`.
+    Assign(FieldAssign),
+    /// A spread of a base object, like `..base`.
+    Spread(ObjectSpread),
+}
+
+/// Parse an object field.
+///
+/// # Examples
+///
+/// ```rust
+/// use rune::{testing, ast};
+///
+/// testing::roundtrip::<ast::ObjectField>("foo");
+/// testing::roundtrip::<ast::ObjectField>("\"foo\": 42");
+/// testing::roundtrip::<ast::ObjectField>("..base");
+/// ```
+impl Parse for ObjectField {
+    fn parse(p: &mut Parser) -> Result<Self, ParseError> {
+        Ok(match p.nth(0)? {
+            K![..] => Self::Spread(p.parse()?),
+            _ => Self::Assign(p.parse()?),
+        })
+    }
+}
+
+/// The spread of a base object, whose fields are copied into the object
+/// being constructed.
+#[derive(Debug, Clone, PartialEq, Eq, Parse, ToTokens, Spanned)]
+pub struct ObjectSpread {
+    /// The `..` token.
+    pub dot_dot: T![..],
+    /// The base object being spread.
+    pub expr: ast::Expr,
+}
+
 /// A literal object field.
 #[derive(Debug, Clone, PartialEq, Eq, ToTokens, Spanned)]
 pub struct FieldAssign {
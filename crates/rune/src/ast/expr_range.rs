@@ -0,0 +1,61 @@
+use crate::ast;
+use crate::{Parse, ParseError, Parser, Peek, Peeker, Spanned, ToTokens};
+
+/// A range expression `a..b` or `a..=b`.
+///
+/// Range expressions bind more loosely than any binary operator, so `0..n + 1`
+/// is the same as `0..(n + 1)`.
+///
+/// # Examples
+///
+/// ```rust
+/// use rune::{testing, ast};
+///
+/// let expr = testing::roundtrip::<ast::Expr>("0..10");
+/// assert!(matches!(expr, ast::Expr::Range(r) if matches!(r.limits, ast::ExprRangeLimits::HalfOpen(..))));
+///
+/// let expr = testing::roundtrip::<ast::Expr>("0..=10");
+/// assert!(matches!(expr, ast::Expr::Range(r) if matches!(r.limits, ast::ExprRangeLimits::Closed(..))));
+///
+/// let expr = testing::roundtrip::<ast::Expr>("a + 1..b * 2");
+/// assert!(matches!(expr, ast::Expr::Range(..)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, ToTokens, Spanned)]
+pub struct ExprRange {
+    /// Attributes associated with the range expression.
+    #[rune(iter)]
+    pub attributes: Vec<ast::Attribute>,
+    /// The start of the range.
+    pub from: ast::Expr,
+    /// The limits of the range.
+    pub limits: ExprRangeLimits,
+    /// The end of the range.
+    pub to: ast::Expr,
+}
+
+expr_parse!(Range, ExprRange, "range expression");
+
+/// The limits of the specified range.
+#[derive(Debug, Clone, PartialEq, Eq, ToTokens, Spanned)]
+pub enum ExprRangeLimits {
+    /// Half-open range expression `a..b`.
+    HalfOpen(T![..]),
+    /// Closed expression `a..=b`.
+    Closed(T![..=]),
+}
+
+impl Parse for ExprRangeLimits {
+    fn parse(p: &mut Parser<'_>) -> Result<Self, ParseError> {
+        Ok(match p.nth(0)? {
+            K![..] => Self::HalfOpen(p.parse()?),
+            K![..=] => Self::Closed(p.parse()?),
+            _ => return Err(ParseError::expected(&p.tok_at(0)?, "range limits")),
+        })
+    }
+}
+
+impl Peek for ExprRangeLimits {
+    fn peek(p: &mut Peeker<'_>) -> bool {
+        matches!(p.nth(0), K![..] | K![..=])
+    }
+}
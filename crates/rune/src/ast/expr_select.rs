@@ -1,6 +1,6 @@
 use crate::ast;
 use crate::ast::utils;
-use crate::{Parse, ParseError, Parser, Spanned, ToTokens};
+use crate::{Parse, ParseError, Parser, Peek, Peeker, Spanned, ToTokens};
 
 /// A `select` expression that selects over a collection of futures.
 ///
@@ -22,6 +22,15 @@ use crate::{Parse, ParseError, Parser, Spanned, ToTokens};
 /// assert!(matches!(select.branches.get(1), Some(&(ast::ExprSelectBranch::Pat(..), Some(..)))));
 /// assert!(matches!(select.branches.get(2), Some(&(ast::ExprSelectBranch::Pat(..), None))));
 /// assert!(matches!(select.branches.get(3), Some(&(ast::ExprSelectBranch::Default(..), None))));
+///
+/// let select = testing::roundtrip::<ast::ExprSelect>(r#"
+/// select {
+///     _ = a => 0,
+///     default(duration) => ()
+/// }
+/// "#);
+///
+/// assert!(matches!(select.branches.get(1), Some(&(ast::ExprSelectBranch::Default(ref def), None)) if def.timeout.is_some()));
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, ToTokens, Spanned)]
 pub struct ExprSelect {
@@ -123,8 +132,30 @@ pub struct ExprSelectPatBranch {
 pub struct ExprDefaultBranch {
     /// The `default` keyword.
     pub default: T![default],
+    /// The duration after which the default branch is taken if none of the
+    /// futures have completed.
+    #[rune(iter)]
+    pub timeout: Option<ExprSelectTimeout>,
     /// `=>`.
     pub rocket: T![=>],
     /// The body of the expression.
     pub body: ast::Expr,
 }
+
+/// The timeout of a default branch, like `(duration)` in
+/// `default(duration) => ()`.
+#[derive(Debug, Clone, PartialEq, Eq, ToTokens, Parse, Spanned)]
+pub struct ExprSelectTimeout {
+    /// The open parenthesis.
+    pub open: T!['('],
+    /// The expression that should evaluate to a duration.
+    pub duration: ast::Expr,
+    /// The close parenthesis.
+    pub close: T![')'],
+}
+
+impl Peek for ExprSelectTimeout {
+    fn peek(p: &mut Peeker<'_>) -> bool {
+        matches!(p.nth(0), K!['('])
+    }
+}
@@ -75,7 +75,7 @@ impl OptionSpanned for File {
 ///
 /// fn main() {
 ///     for x in [1,2,3,4,5,6] {
-///         println(`{x}`)
+///         println(`${x}`)
 ///     }
 /// }
 /// "#);
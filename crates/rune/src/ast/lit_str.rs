@@ -92,6 +92,12 @@ impl LitStr {
                         return Err(ResolveError::new(Span::new(start, end), kind));
                     }
                 },
+                '{' | '}' if *with_template => {
+                    // NB: the lexer makes sure that braces in templates are
+                    // doubled.
+                    it.next();
+                    Some(c)
+                }
                 c => Some(c),
             });
         }
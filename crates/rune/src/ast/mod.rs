@@ -48,6 +48,7 @@ mod expr_block;
 mod expr_break;
 mod expr_call;
 mod expr_closure;
+mod expr_continue;
 mod expr_field_access;
 mod expr_for;
 mod expr_group;
@@ -58,6 +59,7 @@ mod expr_lit;
 mod expr_loop;
 mod expr_match;
 mod expr_object;
+mod expr_range;
 mod expr_return;
 mod expr_select;
 mod expr_try;
@@ -107,6 +109,7 @@ pub use self::expr_block::ExprBlock;
 pub use self::expr_break::{ExprBreak, ExprBreakValue};
 pub use self::expr_call::ExprCall;
 pub use self::expr_closure::ExprClosure;
+pub use self::expr_continue::ExprContinue;
 pub use self::expr_field_access::{ExprField, ExprFieldAccess};
 pub use self::expr_for::ExprFor;
 pub use self::expr_group::ExprGroup;
@@ -116,7 +119,10 @@ pub use self::expr_let::ExprLet;
 pub use self::expr_lit::ExprLit;
 pub use self::expr_loop::ExprLoop;
 pub use self::expr_match::{ExprMatch, ExprMatchBranch};
-pub use self::expr_object::{AnonExprObject, ExprObject, FieldAssign, ObjectIdent, ObjectKey};
+pub use self::expr_object::{
+    AnonExprObject, ExprObject, FieldAssign, ObjectField, ObjectIdent, ObjectKey, ObjectSpread,
+};
+pub use self::expr_range::{ExprRange, ExprRangeLimits};
 pub use self::expr_return::ExprReturn;
 pub use self::expr_select::{ExprSelect, ExprSelectBranch};
 pub use self::expr_try::ExprTry;
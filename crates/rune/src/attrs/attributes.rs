@@ -83,6 +83,44 @@ impl Attributes {
         }
     }
 
+    /// Try to parse all attributes with the given type.
+    pub(crate) fn try_parse_all<T>(&mut self) -> Result<Vec<T>, ParseError>
+    where
+        T: Attribute + Parse,
+    {
+        let mut matched = Vec::new();
+
+        for index in self.unused.iter().copied() {
+            let a = match self.attributes.get(index) {
+                Some(a) => a,
+                None => continue,
+            };
+
+            let ident = match a.path.try_as_ident() {
+                Some(ident) => ident,
+                None => continue,
+            };
+
+            let ident = ident.resolve(&self.storage, &self.source)?;
+
+            if ident != T::PATH {
+                continue;
+            }
+
+            let mut parser = Parser::from_token_stream(&a.input);
+            matched.push((index, parser.parse::<T>()?));
+            parser.eof()?;
+        }
+
+        Ok(matched
+            .into_iter()
+            .map(|(index, matched)| {
+                self.unused.remove(&index);
+                matched
+            })
+            .collect())
+    }
+
     /// Get the span of the first remaining attribute.
     pub(crate) fn remaining(&self) -> Option<Span> {
         for i in self.unused.iter().copied() {
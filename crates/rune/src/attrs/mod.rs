@@ -50,3 +50,18 @@ impl Attribute for BuiltIn {
     /// Must match the specified name.
     const PATH: &'static str = "builtin";
 }
+
+/// A doc string, like `#[doc = "Hello"]`. This is what doc comments like
+/// `/// Hello` are lexed into.
+#[derive(Parse)]
+pub(crate) struct Doc {
+    /// The `=` token.
+    pub eq: T![=],
+    /// The doc string.
+    pub doc_string: ast::LitStr,
+}
+
+impl Attribute for Doc {
+    /// Must match the specified name.
+    const PATH: &'static str = "doc";
+}
@@ -19,6 +19,7 @@ impl AssembleClosure for ast::Block {
         self.assemble(c, Needs::Value)?;
         c.clean_last_scope(span, guard, Needs::Value)?;
         c.asm.push(Inst::Return, span);
+        c.warn_unused_variables();
         Ok(())
     }
 }
@@ -33,10 +34,19 @@ impl Assemble for ast::Block {
         let scopes_count = c.scopes.push_child(span)?;
 
         let mut last = None::<(&ast::Expr, bool)>;
+        let mut unreachable = Unreachable::default();
 
         for stmt in &self.statements {
             let (expr, term) = match stmt {
                 ast::Stmt::Local(local) => {
+                    // NB: the pending expression must be compiled first to
+                    // preserve the order of evaluation.
+                    if let Some((stmt, _)) = last.take() {
+                        unreachable.check(c, stmt.span());
+                        stmt.assemble(c, Needs::None)?;
+                    }
+
+                    unreachable.check(c, local.span());
                     local.assemble(c, Needs::None)?;
                     continue;
                 }
@@ -46,11 +56,14 @@ impl Assemble for ast::Block {
 
             if let Some((stmt, _)) = std::mem::replace(&mut last, Some((expr, term))) {
                 // NB: terminated expressions do not need to produce a value.
+                unreachable.check(c, stmt.span());
                 stmt.assemble(c, Needs::None)?;
             }
         }
 
         let produced = if let Some((expr, term)) = last {
+            unreachable.check(c, expr.span());
+
             if term {
                 expr.assemble(c, Needs::None)?;
                 false
@@ -63,6 +76,7 @@ impl Assemble for ast::Block {
         };
 
         let scope = c.scopes.pop(scopes_count, span)?;
+        c.locals_drop(scope.local_offsets(), span);
 
         if needs.value() {
             if produced {
@@ -82,3 +96,25 @@ impl Assemble for ast::Block {
         Ok(())
     }
 }
+
+/// Helper to warn about the first unreachable statement in a block.
+#[derive(Default)]
+struct Unreachable {
+    /// The span of the last statement assembled.
+    last: Option<Span>,
+    /// If an unreachable statement has already been reported.
+    reported: bool,
+}
+
+impl Unreachable {
+    /// Check if the statement with the given span which is about to be
+    /// assembled is unreachable.
+    fn check(&mut self, c: &mut Compiler<'_>, span: Span) {
+        if let Some(cause) = self.last.replace(span) {
+            if !self.reported && c.asm.is_unreachable() {
+                c.warnings.unreachable(c.source_id, span, cause);
+                self.reported = true;
+            }
+        }
+    }
+}
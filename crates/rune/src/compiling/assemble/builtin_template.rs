@@ -1,5 +1,6 @@
 use crate::compiling::assemble::prelude::*;
 use crate::query::BuiltInTemplate;
+use runestick::format;
 
 /// Compile a literal template string.
 impl Assemble for BuiltInTemplate {
@@ -11,8 +12,8 @@ impl Assemble for BuiltInTemplate {
         let mut size_hint = 0;
         let mut expansions = 0;
 
-        for expr in &self.exprs {
-            if let ast::Expr::Lit(expr_lit) = expr {
+        for (expr, format_type) in &self.exprs {
+            if let (ast::Expr::Lit(expr_lit), None) = (expr, format_type) {
                 if let ast::ExprLit {
                     lit: ast::Lit::Str(s),
                     ..
@@ -30,6 +31,20 @@ impl Assemble for BuiltInTemplate {
 
             expansions += 1;
             expr.assemble(c, Needs::Value)?;
+
+            if let Some((ident, format_type)) = format_type {
+                let spec = format::FormatSpec::new(
+                    format::Flags::default(),
+                    ' ',
+                    format::Alignment::default(),
+                    None,
+                    None,
+                    *format_type,
+                );
+
+                c.asm.push(Inst::Format { spec }, ident.span());
+            }
+
             c.scopes.decl_anon(span)?;
         }
 
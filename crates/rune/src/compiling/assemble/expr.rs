@@ -35,6 +35,9 @@ impl Assemble for ast::Expr {
             ast::Expr::Binary(expr_binary) => {
                 expr_binary.assemble(c, needs)?;
             }
+            ast::Expr::Range(expr_range) => {
+                expr_range.assemble(c, needs)?;
+            }
             ast::Expr::If(expr_if) => {
                 expr_if.assemble(c, needs)?;
             }
@@ -44,6 +47,9 @@ impl Assemble for ast::Expr {
             ast::Expr::Break(expr_break) => {
                 expr_break.assemble(c, needs)?;
             }
+            ast::Expr::Continue(expr_continue) => {
+                expr_continue.assemble(c, needs)?;
+            }
             ast::Expr::Yield(expr_yield) => {
                 expr_yield.assemble(c, needs)?;
             }
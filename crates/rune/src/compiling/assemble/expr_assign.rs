@@ -6,6 +6,8 @@ impl Assemble for ast::ExprAssign {
         let span = self.span();
         log::trace!("ExprAssign => {:?}", c.source.source(span));
 
+        warn_assign_temporary(c, &self.lhs);
+
         let supported = match &self.lhs {
             // <var> = <value>
             ast::Expr::Path(path) if path.rest.is_empty() => {
@@ -96,3 +98,33 @@ impl Assemble for ast::ExprAssign {
         Ok(())
     }
 }
+
+/// Warn if the projections being assigned to are rooted in a temporary value,
+/// like `(1, 2).0 = 5`, since such assignments are always lost.
+///
+/// NB: values returned from calls are references to the same value, so
+/// something like `get_pair().0 = 5` is a valid assignment.
+pub(super) fn warn_assign_temporary(c: &mut Compiler<'_>, lhs: &ast::Expr) {
+    let mut current = match lhs {
+        ast::Expr::FieldAccess(field_access) => &field_access.expr,
+        ast::Expr::Index(expr_index) => &expr_index.target,
+        _ => return,
+    };
+
+    loop {
+        current = match current {
+            ast::Expr::FieldAccess(field_access) => &field_access.expr,
+            ast::Expr::Index(expr_index) => &expr_index.target,
+            ast::Expr::Group(expr_group) => &expr_group.expr,
+            ast::Expr::Lit(..)
+            | ast::Expr::Tuple(..)
+            | ast::Expr::Vec(..)
+            | ast::Expr::Object(..) => {
+                c.warnings
+                    .assign_to_temporary(c.source_id, current.span(), c.context());
+                return;
+            }
+            _ => return,
+        };
+    }
+}
@@ -28,6 +28,15 @@ impl Assemble for ast::ExprBinary {
             return Ok(());
         }
 
+        // NB: binary expressions which only consist of constants are evaluated
+        // at compile time, so that only the resulting value is pushed.
+        if self.is_const() {
+            if let Some(value) = c.eval_const(self)? {
+                value.assemble_const(c, needs, span)?;
+                return Ok(());
+            }
+        }
+
         // NB: need to declare these as anonymous local variables so that they
         // get cleaned up in case there is an early break (return, try, ...).
         self.lhs.assemble(c, Needs::Value)?;
@@ -45,6 +54,7 @@ impl Assemble for ast::ExprBinary {
             ast::BinOp::Gte => Inst::Op { op: InstOp::Gte },
             ast::BinOp::Is => Inst::Op { op: InstOp::Is },
             ast::BinOp::IsNot => Inst::Op { op: InstOp::IsNot },
+            ast::BinOp::In => Inst::Op { op: InstOp::In },
             ast::BinOp::And => Inst::Op { op: InstOp::And },
             ast::BinOp::Or => Inst::Op { op: InstOp::Or },
             ast::BinOp::Add => Inst::Op { op: InstOp::Add },
@@ -52,6 +62,7 @@ impl Assemble for ast::ExprBinary {
             ast::BinOp::Div => Inst::Op { op: InstOp::Div },
             ast::BinOp::Mul => Inst::Op { op: InstOp::Mul },
             ast::BinOp::Rem => Inst::Op { op: InstOp::Rem },
+            ast::BinOp::Pow => Inst::Op { op: InstOp::Pow },
             ast::BinOp::BitAnd => Inst::Op { op: InstOp::BitAnd },
             ast::BinOp::BitXor => Inst::Op { op: InstOp::BitXor },
             ast::BinOp::BitOr => Inst::Op { op: InstOp::BitOr },
@@ -66,7 +77,9 @@ impl Assemble for ast::ExprBinary {
             }
         };
 
-        c.asm.push(inst, span);
+        // NB: the operation is associated with the operator, so that errors
+        // raised by it point to the operator rather than the whole expression.
+        c.asm.push(inst, self.op_span());
 
         // NB: we put it here to preserve the call in case it has side effects.
         // But if we don't need the value, then pop it from the stack.
@@ -136,6 +149,13 @@ fn compile_assign_binop(
 ) -> CompileResult<()> {
     let span = lhs.span().join(rhs.span());
 
+    super::expr_assign::warn_assign_temporary(c, lhs);
+
+    // <expr>[<index>] <op> <value>
+    if let ast::Expr::Index(expr_index) = lhs {
+        return compile_assign_index_binop(c, expr_index, rhs, bin_op, span, needs);
+    }
+
     let supported = match lhs {
         // <var> <op> <expr>
         ast::Expr::Path(path) if path.rest.is_empty() => {
@@ -215,3 +235,68 @@ fn compile_assign_binop(
 
     Ok(())
 }
+
+/// Compile a compound assignment to an index, like `a[i] += v`.
+///
+/// This is performed as an index get followed by an index set, where the
+/// target and index expressions are only evaluated once.
+fn compile_assign_index_binop(
+    c: &mut Compiler<'_>,
+    expr_index: &ast::ExprIndex,
+    rhs: &ast::Expr,
+    bin_op: ast::BinOp,
+    span: Span,
+    needs: Needs,
+) -> CompileResult<()> {
+    let op = match bin_op {
+        ast::BinOp::AddAssign => InstOp::Add,
+        ast::BinOp::SubAssign => InstOp::Sub,
+        ast::BinOp::MulAssign => InstOp::Mul,
+        ast::BinOp::DivAssign => InstOp::Div,
+        ast::BinOp::RemAssign => InstOp::Rem,
+        ast::BinOp::BitAndAssign => InstOp::BitAnd,
+        ast::BinOp::BitXorAssign => InstOp::BitXor,
+        ast::BinOp::BitOrAssign => InstOp::BitOr,
+        ast::BinOp::ShlAssign => InstOp::Shl,
+        ast::BinOp::ShrAssign => InstOp::Shr,
+        _ => {
+            return Err(CompileError::new(
+                span,
+                CompileErrorKind::UnsupportedBinaryExpr,
+            ));
+        }
+    };
+
+    expr_index.target.assemble(c, Needs::Value)?;
+    let target = c.scopes.decl_anon(span)?;
+
+    expr_index.index.assemble(c, Needs::Value)?;
+    let index = c.scopes.decl_anon(span)?;
+
+    c.asm.push(Inst::Copy { offset: target }, span);
+    c.asm.push(Inst::Copy { offset: index }, span);
+    c.asm.push(Inst::IndexGet, span);
+    let value = c.scopes.decl_anon(span)?;
+
+    rhs.assemble(c, Needs::Value)?;
+    c.asm.push(Inst::Op { op }, span);
+
+    // NB: reorder `<target> <index> <value>` into the `<value> <target>
+    // <index>` layout expected by `IndexSet`.
+    c.asm.push(
+        Inst::Swap {
+            a: target,
+            b: value,
+        },
+        span,
+    );
+    c.asm.push(Inst::Swap { a: index, b: value }, span);
+    c.asm.push(Inst::IndexSet, span);
+    c.scopes.undecl_anon(span, 3)?;
+
+    if needs.value() {
+        c.asm.push(Inst::unit(), span);
+    }
+
+    Ok(())
+}
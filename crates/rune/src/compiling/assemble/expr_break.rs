@@ -21,6 +21,15 @@ impl Assemble for ast::ExprBreak {
         let (last_loop, to_drop, has_value) = if let Some(expr) = &self.expr {
             match expr {
                 ast::ExprBreakValue::Expr(expr) => {
+                    if !current_loop.break_value {
+                        return Err(CompileError::new(
+                            span,
+                            CompileErrorKind::BreakValueOutsideOfLoop {
+                                loop_span: current_loop.span,
+                            },
+                        ));
+                    }
+
                     expr.assemble(c, current_loop.needs)?;
                     (current_loop, current_loop.drop.into_iter().collect(), true)
                 }
@@ -39,12 +48,14 @@ impl Assemble for ast::ExprBreak {
             c.asm.push(Inst::Drop { offset }, span);
         }
 
-        let vars = c
-            .scopes
-            .total_var_count(span)?
+        let total_var_count = c.scopes.total_var_count(span)?;
+
+        let vars = total_var_count
             .checked_sub(last_loop.total_var_count)
             .ok_or_else(|| CompileError::msg(&span, "var count should be larger"))?;
 
+        c.locals_drop(last_loop.total_var_count..total_var_count, span);
+
         if last_loop.needs.value() {
             if has_value {
                 c.locals_clean(vars, span);
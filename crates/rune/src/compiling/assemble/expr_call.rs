@@ -107,16 +107,10 @@ impl Assemble for ast::ExprCall {
 
         match &meta.kind {
             CompileMetaKind::UnitStruct { .. } | CompileMetaKind::UnitVariant { .. } => {
-                if 0 != self.args.len() {
-                    return Err(CompileError::new(
-                        span,
-                        CompileErrorKind::UnsupportedArgumentCount {
-                            meta: meta.clone(),
-                            expected: 0,
-                            actual: self.args.len(),
-                        },
-                    ));
-                }
+                return Err(CompileError::new(
+                    span,
+                    CompileErrorKind::UnsupportedUnitCall { meta: meta.clone() },
+                ));
             }
             CompileMetaKind::TupleStruct { tuple, .. }
             | CompileMetaKind::TupleVariant { tuple, .. } => {
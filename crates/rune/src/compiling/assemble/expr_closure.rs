@@ -41,6 +41,7 @@ impl AssembleClosure for ast::ExprClosure {
         };
 
         self.body.assemble(c, Needs::Value)?;
+        c.locals_drop(0..count, span);
 
         if count != 0 {
             c.asm.push(Inst::Clean { count }, span);
@@ -49,6 +50,7 @@ impl AssembleClosure for ast::ExprClosure {
         c.asm.push(Inst::Return, span);
 
         c.scopes.pop_last(span)?;
+        c.warn_unused_variables();
         Ok(())
     }
 }
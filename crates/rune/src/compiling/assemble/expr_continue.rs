@@ -0,0 +1,50 @@
+use crate::compiling::assemble::prelude::*;
+
+/// Compile a continue expression.
+impl Assemble for ast::ExprContinue {
+    fn assemble(&self, c: &mut Compiler<'_>, _: Needs) -> CompileResult<()> {
+        let span = self.span();
+        log::trace!("ExprContinue => {:?}", c.source.source(span));
+
+        let current_loop = match c.loops.last() {
+            Some(current_loop) => current_loop,
+            None => {
+                return Err(CompileError::new(
+                    span,
+                    CompileErrorKind::ContinueOutsideOfLoop,
+                ));
+            }
+        };
+
+        let (last_loop, to_drop) = if let Some(label) = self.label {
+            let (last_loop, mut to_drop) =
+                c.loops.walk_until_label(c.storage, &*c.source, label)?;
+
+            // NB: the loop we continue keeps its temporary, since it's used in
+            // the next iteration.
+            if last_loop.drop.is_some() {
+                to_drop.pop();
+            }
+
+            (last_loop, to_drop)
+        } else {
+            (current_loop, Vec::new())
+        };
+
+        // Drop temporaries of the loops we are breaking out of.
+        for offset in to_drop {
+            c.asm.push(Inst::Drop { offset }, span);
+        }
+
+        let total_var_count = c.scopes.total_var_count(span)?;
+
+        let vars = total_var_count
+            .checked_sub(last_loop.continue_var_count)
+            .ok_or_else(|| CompileError::msg(&span, "var count should be larger"))?;
+
+        c.locals_drop(last_loop.continue_var_count..total_var_count, span);
+        c.locals_pop(vars, span);
+        c.asm.jump(last_loop.continue_label, span);
+        Ok(())
+    }
+}
@@ -29,19 +29,29 @@ impl Assemble for ast::ExprFor {
             (iter_offset, loop_scope_expected)
         };
 
-        let _guard = c.loops.push(Loop {
-            label: self.label.map(|(label, _)| label),
-            break_label,
-            total_var_count,
-            needs,
-            drop: Some(iter_offset),
-        });
+        // NB: a binding to a single variable is stored directly in the loop
+        // variable, anything else is bound from an anonymous slot in each
+        // iteration.
+        let local = match &self.binding {
+            ast::Pat::PatPath(path) => {
+                let named = c.convert_path_to_named(&path.path)?;
+
+                match c.lookup_meta(path.span(), &named)? {
+                    Some(..) => None,
+                    None => named.as_local().map(Box::<str>::from),
+                }
+            }
+            _ => None,
+        };
 
-        // Declare named loop variable.
+        // Declare the loop variable.
         let binding_offset = {
             c.asm.push(Inst::unit(), self.iter.span());
-            let name = self.var.resolve(&c.storage, &*c.source)?;
-            c.scopes.decl_var(name.as_ref(), self.var.span())?
+
+            match &local {
+                Some(name) => c.scopes.decl_var(name, self.binding.span())?,
+                None => c.scopes.decl_anon(self.binding.span())?,
+            }
         };
 
         // Declare storage for memoized `next` instance fn.
@@ -72,6 +82,20 @@ impl Assemble for ast::ExprFor {
             None
         };
 
+        // NB: continuing keeps the iterator, the loop variable and the
+        // memoized `next` function around.
+        let _guard = c.loops.push(Loop {
+            span,
+            break_value: false,
+            label: self.label.map(|(label, _)| label),
+            continue_label: start_label,
+            continue_var_count: c.scopes.total_var_count(span)?,
+            break_label,
+            total_var_count,
+            needs,
+            drop: Some(iter_offset),
+        });
+
         c.asm.label(start_label)?;
 
         // Use the memoized loop variable.
@@ -98,7 +122,7 @@ impl Assemble for ast::ExprFor {
                 Inst::Replace {
                     offset: binding_offset,
                 },
-                self.var.span(),
+                self.binding.span(),
             );
         } else {
             // call the `next` function to get the next level of iteration, bind the
@@ -122,7 +146,7 @@ impl Assemble for ast::ExprFor {
                 Inst::Replace {
                     offset: binding_offset,
                 },
-                self.var.span(),
+                self.binding.span(),
             );
         }
 
@@ -133,7 +157,7 @@ impl Assemble for ast::ExprFor {
                 Inst::Copy {
                     offset: binding_offset,
                 },
-                self.var.span(),
+                self.binding.span(),
             );
             c.asm.push(Inst::IsValue, self.span());
             c.asm.jump_if_not(end_label, self.span());
@@ -141,7 +165,7 @@ impl Assemble for ast::ExprFor {
                 Inst::Copy {
                     offset: binding_offset,
                 },
-                self.var.span(),
+                self.binding.span(),
             );
             // unwrap the optional value.
             c.asm.push(Inst::Unwrap, self.span());
@@ -149,11 +173,54 @@ impl Assemble for ast::ExprFor {
                 Inst::Replace {
                     offset: binding_offset,
                 },
-                self.var.span(),
+                self.binding.span(),
             );
         }
 
+        let binding_scope = if local.is_none() {
+            let expected = c.scopes.push_child(span)?;
+            let binding_span = self.binding.span();
+
+            let load = |c: &mut Compiler, needs: Needs| {
+                if needs.value() {
+                    c.asm.push(
+                        Inst::Copy {
+                            offset: binding_offset,
+                        },
+                        binding_span,
+                    );
+                }
+
+                Ok(())
+            };
+
+            let false_label = c.asm.new_label("for_panic");
+
+            if c.compile_pat(&self.binding, false_label, &load)? {
+                let ok_label = c.asm.new_label("for_ok");
+                c.asm.jump(ok_label, span);
+                c.asm.label(false_label)?;
+                c.asm.push(
+                    Inst::Panic {
+                        reason: runestick::PanicReason::UnmatchedPattern,
+                    },
+                    binding_span,
+                );
+
+                c.asm.label(ok_label)?;
+            }
+
+            Some(expected)
+        } else {
+            None
+        };
+
         self.body.assemble(c, Needs::None)?;
+
+        if let Some(expected) = binding_scope {
+            c.clean_last_scope(span, expected, Needs::None)?;
+        }
+
         c.asm.jump(start_label, span);
         c.asm.label(end_label)?;
 
@@ -6,6 +6,28 @@ impl Assemble for ast::ExprIf {
         let span = self.span();
         log::trace!("ExprIf => {:?}", c.source.source(span));
 
+        // NB: if the condition is a constant, only the branch which is taken
+        // is compiled. The branch which is dropped is not compiled at all, so
+        // it will not be checked for errors.
+        if let ast::Condition::Expr(expr) = &self.condition {
+            match c.eval_const_expr(expr)? {
+                Some(ConstValue::Bool(true)) => {
+                    self.block.assemble(c, needs)?;
+                    return Ok(());
+                }
+                Some(ConstValue::Bool(false)) if self.expr_else_ifs.is_empty() => {
+                    if let Some(fallback) = &self.expr_else {
+                        fallback.block.assemble(c, needs)?;
+                    } else if needs.value() {
+                        c.asm.push(Inst::unit(), span);
+                    }
+
+                    return Ok(());
+                }
+                _ => (),
+            }
+        }
+
         let then_label = c.asm.new_label("if_then");
         let end_label = c.asm.new_label("if_end");
 
@@ -9,10 +9,16 @@ impl Assemble for ast::ExprLoop {
         let start_label = c.asm.new_label("loop_start");
         let end_label = c.asm.new_label("loop_end");
 
+        let total_var_count = c.scopes.total_var_count(span)?;
+
         let _guard = c.loops.push(Loop {
+            span,
+            break_value: true,
             label: self.label.map(|(label, _)| label),
+            continue_label: start_label,
+            continue_var_count: total_var_count,
             break_label: end_label,
-            total_var_count: c.scopes.total_var_count(span)?,
+            total_var_count,
             needs,
             drop: None,
         });
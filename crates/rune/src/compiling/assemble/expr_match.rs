@@ -60,11 +60,12 @@ impl Assemble for ast::ExprMatch {
 
         // what to do in case nothing matches and the pattern doesn't have any
         // default match branch.
-        if needs.value() {
-            c.asm.push(Inst::unit(), span);
-        }
-
-        c.asm.jump(end_label, span);
+        c.asm.push(
+            Inst::Panic {
+                reason: runestick::PanicReason::UnmatchedPattern,
+            },
+            span,
+        );
 
         let mut it = self.branches.iter().zip(&branches).peekable();
 
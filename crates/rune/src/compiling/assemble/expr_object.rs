@@ -7,11 +7,27 @@ impl Assemble for ast::ExprObject {
         let span = self.span();
         log::trace!("ExprObject => {:?} {:?}", c.source.source(span), needs);
 
+        let mut spread = None;
+        let mut assignments = Vec::new();
+
+        for (n, (field, _)) in self.assignments.iter().enumerate() {
+            match field {
+                ast::ObjectField::Assign(assign) => assignments.push(assign),
+                ast::ObjectField::Spread(s) => {
+                    if n != 0 {
+                        return Err(CompileError::new(s, CompileErrorKind::ObjectSpreadNotFirst));
+                    }
+
+                    spread = Some(s);
+                }
+            }
+        }
+
         let mut keys = Vec::<Box<str>>::new();
         let mut check_keys = Vec::new();
         let mut keys_dup = HashMap::new();
 
-        for (assign, _) in &self.assignments {
+        for assign in &assignments {
             let span = assign.span();
             let key = assign.key.resolve(&c.storage, &*c.source)?;
             keys.push(key.as_ref().into());
@@ -28,16 +44,12 @@ impl Assemble for ast::ExprObject {
             }
         }
 
-        for (assign, _) in &self.assignments {
-            let span = assign.span();
+        if let Some(spread) = spread {
+            return assemble_spread(self, c, spread, &assignments, check_keys, needs);
+        }
 
-            if let Some((_, expr)) = &assign.assign {
-                expr.assemble(c, Needs::Value)?;
-            } else {
-                let key = assign.key.resolve(&c.storage, &*c.source)?;
-                let var = c.scopes.get_var(&*key, c.source_id, c.visitor, span)?;
-                var.copy(&mut c.asm, span, format!("name `{}`", key));
-            }
+        for assign in &assignments {
+            assemble_field_value(c, assign)?;
         }
 
         let slot = c.unit.new_static_object_keys(span, &keys)?;
@@ -60,19 +72,37 @@ impl Assemble for ast::ExprObject {
 
                 match &meta.kind {
                     CompileMetaKind::UnitStruct { .. } => {
-                        check_object_fields(Some(&HashSet::new()), check_keys, span, &meta.item)?;
+                        check_object_fields(
+                            Some(&HashSet::new()),
+                            check_keys,
+                            false,
+                            span,
+                            &meta.item,
+                        )?;
 
                         let hash = Hash::type_hash(&meta.item);
                         c.asm.push(Inst::UnitStruct { hash }, span);
                     }
                     CompileMetaKind::Struct { object, .. } => {
-                        check_object_fields(object.fields.as_ref(), check_keys, span, &meta.item)?;
+                        check_object_fields(
+                            object.fields.as_ref(),
+                            check_keys,
+                            false,
+                            span,
+                            &meta.item,
+                        )?;
 
                         let hash = Hash::type_hash(&meta.item);
                         c.asm.push(Inst::Struct { hash, slot }, span);
                     }
                     CompileMetaKind::StructVariant { object, .. } => {
-                        check_object_fields(object.fields.as_ref(), check_keys, span, &meta.item)?;
+                        check_object_fields(
+                            object.fields.as_ref(),
+                            check_keys,
+                            false,
+                            span,
+                            &meta.item,
+                        )?;
 
                         let hash = Hash::type_hash(&meta.item);
                         c.asm.push(Inst::StructVariant { hash, slot }, span);
@@ -100,9 +130,113 @@ impl Assemble for ast::ExprObject {
     }
 }
 
+/// Assemble the value of a single field assignment.
+fn assemble_field_value(c: &mut Compiler<'_>, assign: &ast::FieldAssign) -> CompileResult<()> {
+    let span = assign.span();
+
+    if let Some((_, expr)) = &assign.assign {
+        expr.assemble(c, Needs::Value)?;
+    } else {
+        let key = assign.key.resolve(&c.storage, &*c.source)?;
+        let var = c.scopes.get_var(&*key, c.source_id, c.visitor, span)?;
+        var.copy(&mut c.asm, span, format!("name `{}`", key));
+    }
+
+    Ok(())
+}
+
+/// Assemble an object with a spread, like `#{ ..base, x: 1 }`.
+///
+/// This copies the base and then assigns each field to the copy.
+fn assemble_spread(
+    object: &ast::ExprObject,
+    c: &mut Compiler<'_>,
+    spread: &ast::ObjectSpread,
+    assignments: &[&ast::FieldAssign],
+    check_keys: Vec<(Box<str>, Span)>,
+    needs: Needs,
+) -> CompileResult<()> {
+    let span = object.span();
+
+    let hash = match &object.ident {
+        ast::ObjectIdent::Named(path) => {
+            let named = c.convert_path_to_named(path)?;
+
+            let meta = match c.lookup_meta(path.span(), &named)? {
+                Some(meta) => meta,
+                None => {
+                    return Err(CompileError::new(
+                        span,
+                        CompileErrorKind::MissingType {
+                            item: named.item.clone(),
+                        },
+                    ));
+                }
+            };
+
+            match &meta.kind {
+                CompileMetaKind::Struct { object, .. } => {
+                    check_object_fields(
+                        object.fields.as_ref(),
+                        check_keys,
+                        true,
+                        span,
+                        &meta.item,
+                    )?;
+
+                    Hash::type_hash(&meta.item)
+                }
+                _ => {
+                    return Err(CompileError::new(
+                        span,
+                        CompileErrorKind::UnsupportedLitObject { meta },
+                    ));
+                }
+            }
+        }
+        ast::ObjectIdent::Anonymous(..) => runestick::OBJECT_TYPE.hash,
+    };
+
+    spread.expr.assemble(c, Needs::Value)?;
+    c.asm.push(Inst::Spread { hash }, spread.span());
+    let offset = c.scopes.decl_anon(spread.span())?;
+
+    for assign in assignments {
+        let span = assign.span();
+        let key = assign.key.resolve(&c.storage, &*c.source)?;
+        let slot = c.unit.new_static_string(&assign.key, key.as_ref())?;
+
+        assemble_field_value(c, assign)?;
+        c.scopes.decl_anon(span)?;
+
+        c.asm.push(Inst::Copy { offset }, span);
+        c.scopes.decl_anon(span)?;
+
+        c.asm.push(Inst::String { slot }, span);
+        c.scopes.decl_anon(span)?;
+
+        c.asm.push(Inst::IndexSet, span);
+        c.scopes.undecl_anon(span, 3)?;
+    }
+
+    c.scopes.undecl_anon(span, 1)?;
+
+    if !needs.value() {
+        c.warnings.not_used(c.source_id, span, c.context());
+        c.asm.push(Inst::Pop, span);
+    }
+
+    Ok(())
+}
+
+/// Check that the fields being assigned are the fields of the given type.
+///
+/// If `allow_missing` is set, only some fields need to be assigned, since the
+/// rest are provided by a spread.
 fn check_object_fields(
     fields: Option<&HashSet<Box<str>>>,
     check_keys: Vec<(Box<str>, Span)>,
+    allow_missing: bool,
     span: Span,
     item: &Item,
 ) -> CompileResult<()> {
@@ -128,7 +262,13 @@ fn check_object_fields(
         }
     }
 
-    if let Some(field) = fields.into_iter().next() {
+    if allow_missing {
+        return Ok(());
+    }
+
+    // NB: report the lexically first missing field, so that the error is
+    // stable regardless of hash set iteration order.
+    if let Some(field) = fields.into_iter().min() {
         return Err(CompileError::new(
             span,
             CompileErrorKind::LitObjectMissingField {
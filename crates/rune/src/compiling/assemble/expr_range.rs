@@ -0,0 +1,30 @@
+use crate::compiling::assemble::prelude::*;
+
+/// Compile a range expression.
+impl Assemble for ast::ExprRange {
+    fn assemble(&self, c: &mut Compiler<'_>, needs: Needs) -> CompileResult<()> {
+        let span = self.span();
+        log::trace!("ExprRange => {:?}", c.source.source(span));
+
+        self.from.assemble(c, Needs::Value)?;
+        c.scopes.decl_anon(span)?;
+
+        self.to.assemble(c, Needs::Value)?;
+        c.scopes.decl_anon(span)?;
+
+        let limits = match &self.limits {
+            ast::ExprRangeLimits::HalfOpen(..) => RangeLimits::HalfOpen,
+            ast::ExprRangeLimits::Closed(..) => RangeLimits::Closed,
+        };
+
+        c.asm.push(Inst::Range { limits }, self.limits.span());
+        c.scopes.undecl_anon(span, 2)?;
+
+        if !needs.value() {
+            c.warnings.not_used(c.source_id, span, c.context());
+            c.asm.push(Inst::Pop, span);
+        }
+
+        Ok(())
+    }
+}
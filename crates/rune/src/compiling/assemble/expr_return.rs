@@ -19,9 +19,11 @@ impl Assemble for ast::ExprReturn {
 
         if let Some(expr) = &self.expr {
             expr.assemble(c, Needs::Value)?;
+            c.locals_drop(0..total_var_count, span);
             c.locals_clean(total_var_count, span);
             c.asm.push(Inst::Return, span);
         } else {
+            c.locals_drop(0..total_var_count, span);
             c.locals_pop(total_var_count, span);
             c.asm.push(Inst::ReturnUnit, span);
         }
@@ -5,7 +5,6 @@ impl Assemble for ast::ExprSelect {
     fn assemble(&self, c: &mut Compiler<'_>, needs: Needs) -> CompileResult<()> {
         let span = self.span();
         log::trace!("ExprSelect => {:?}", c.source.source(span));
-        let len = self.branches.len();
         c.contexts.push(span);
 
         let mut default_branch = None;
@@ -37,13 +36,39 @@ impl Assemble for ast::ExprSelect {
             branch.expr.assemble(c, Needs::Value)?;
         }
 
-        c.asm.push(Inst::Select { len }, span);
+        let timeout = default_branch
+            .as_ref()
+            .and_then(|(def, _)| def.timeout.as_ref());
+
+        // NB: the default branch doesn't have a future to select over, but
+        // might time out the select.
+        if let Some(timeout) = timeout {
+            timeout.duration.assemble(c, Needs::Value)?;
+        }
+
+        let len = branches.len();
+        c.asm.push(
+            Inst::Select {
+                len,
+                timeout: timeout.is_some(),
+            },
+            span,
+        );
 
         for (branch, (label, _)) in branches.iter().enumerate() {
             c.asm.jump_if_branch(branch as i64, *label, span);
         }
 
         if let Some((_, label)) = &default_branch {
+            // NB: a timed out select is handled the same as if there were
+            // no futures to select over, once the branch has been popped.
+            if timeout.is_some() {
+                let timeout_label = c.asm.new_label("select_timeout");
+                c.asm
+                    .jump_if_branch(runestick::Select::TIMEOUT_BRANCH, timeout_label, span);
+                c.asm.label(timeout_label)?;
+            }
+
             c.asm.push(Inst::Pop, span);
             c.asm.jump(*label, span);
         }
@@ -15,6 +15,7 @@ impl Assemble for ast::ExprTry {
 
         // Clean up all locals so far and return from the current function.
         let total_var_count = c.scopes.total_var_count(span)?;
+        c.locals_drop(0..total_var_count, span);
         c.locals_clean(total_var_count, span);
         c.asm.push(Inst::Return, span);
 
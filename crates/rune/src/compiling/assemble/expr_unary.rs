@@ -11,10 +11,23 @@ impl Assemble for ast::ExprUnary {
             return Err(CompileError::new(self, CompileErrorKind::UnsupportedRef));
         }
 
+        // NB: negative number literals are folded into a single push. The
+        // literal is negated before it's converted, so that `i64::MIN` which
+        // can't be represented as a positive `i64` is still supported.
         if let (ast::UnOp::Neg, ast::Expr::Lit(expr_lit)) = (self.op, &self.expr) {
             if let ast::Lit::Number(n) = &expr_lit.lit {
-                let n = n.resolve(&c.storage, &*c.source)?.as_i64(span, true)?;
-                c.asm.push(Inst::integer(n), span);
+                let inst = match n.resolve(&c.storage, &*c.source)? {
+                    ast::Number::Float(n) => Inst::float(-n),
+                    number => Inst::integer(number.as_i64(span, true)?),
+                };
+
+                // NB: don't encode unecessary literal.
+                if !needs.value() {
+                    c.warnings.not_used(c.source_id, span, c.context());
+                    return Ok(());
+                }
+
+                c.asm.push(inst, span);
                 return Ok(());
             }
         }
@@ -11,10 +11,16 @@ impl Assemble for ast::ExprWhile {
         let end_label = c.asm.new_label("while_end");
         let break_label = c.asm.new_label("while_break");
 
+        let total_var_count = c.scopes.total_var_count(span)?;
+
         let _guard = c.loops.push(Loop {
+            span,
+            break_value: false,
             label: self.label.map(|(label, _)| label),
+            continue_label: start_label,
+            continue_var_count: total_var_count,
             break_label,
-            total_var_count: c.scopes.total_var_count(span)?,
+            total_var_count,
             needs,
             drop: None,
         });
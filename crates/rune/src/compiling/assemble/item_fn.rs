@@ -35,6 +35,7 @@ impl AssembleFn for ast::ItemFn {
 
         if self.body.statements.is_empty() {
             let total_var_count = c.scopes.total_var_count(span)?;
+            c.locals_drop(0..total_var_count, span);
             c.locals_pop(total_var_count, span);
             c.asm.push(Inst::ReturnUnit, span);
             return Ok(());
@@ -44,17 +45,20 @@ impl AssembleFn for ast::ItemFn {
             self.body.assemble(c, Needs::Value)?;
 
             let total_var_count = c.scopes.total_var_count(span)?;
+            c.locals_drop(0..total_var_count, span);
             c.locals_clean(total_var_count, span);
             c.asm.push(Inst::Return, span);
         } else {
             self.body.assemble(c, Needs::None)?;
 
             let total_var_count = c.scopes.total_var_count(span)?;
+            c.locals_drop(0..total_var_count, span);
             c.locals_pop(total_var_count, span);
             c.asm.push(Inst::ReturnUnit, span);
         }
 
         c.scopes.pop_last(span)?;
+        c.warn_unused_variables();
         Ok(())
     }
 }
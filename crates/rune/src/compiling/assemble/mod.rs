@@ -10,6 +10,7 @@ mod expr_block;
 mod expr_break;
 mod expr_call;
 mod expr_closure;
+mod expr_continue;
 mod expr_field_access;
 mod expr_for;
 mod expr_if;
@@ -19,6 +20,7 @@ mod expr_loop;
 mod expr_match;
 mod expr_object;
 mod expr_path;
+mod expr_range;
 mod expr_return;
 mod expr_select;
 mod expr_try;
@@ -115,6 +115,44 @@ impl Assembly {
             .push((AssemblyInst::PopAndJumpIfNot { count, label }, span));
     }
 
+    /// Test if the next instruction to be pushed is unreachable.
+    ///
+    /// This is the case if the last instruction unconditionally transfers
+    /// control elsewhere, and no jump targets the current offset.
+    pub(crate) fn is_unreachable(&self) -> bool {
+        let diverges = match self.instructions.last() {
+            Some((AssemblyInst::Jump { .. }, _)) => true,
+            Some((AssemblyInst::Raw { raw }, _)) => {
+                matches!(raw, Inst::Return | Inst::ReturnUnit | Inst::Panic { .. })
+            }
+            _ => false,
+        };
+
+        if !diverges {
+            return false;
+        }
+
+        let offset = self.instructions.len();
+
+        let is_target = |label: &Label| {
+            self.instructions.iter().any(|(inst, _)| match inst {
+                AssemblyInst::Jump { label: target }
+                | AssemblyInst::JumpIf { label: target }
+                | AssemblyInst::JumpIfNot { label: target }
+                | AssemblyInst::JumpIfOrPop { label: target }
+                | AssemblyInst::JumpIfNotOrPop { label: target }
+                | AssemblyInst::JumpIfBranch { label: target, .. }
+                | AssemblyInst::PopAndJumpIfNot { label: target, .. } => target == label,
+                AssemblyInst::Raw { .. } => false,
+            })
+        };
+
+        !self
+            .labels
+            .iter()
+            .any(|(label, o)| *o == offset && is_target(label))
+    }
+
     /// Push a raw instruction.
     pub(crate) fn push(&mut self, raw: Inst, span: Span) {
         if let Inst::Call { hash, .. } = raw {
@@ -158,6 +158,8 @@ pub enum CompileErrorKind {
     UnsupportedSelectPattern,
     #[error("unsupported field access")]
     BadFieldAccess,
+    #[error("{meta} has no fields and cannot be called, use it without parentheses")]
+    UnsupportedUnitCall { meta: CompileMeta },
     #[error("wrong number of arguments, expected `{expected}` but got `{actual}`")]
     UnsupportedArgumentCount {
         meta: CompileMeta,
@@ -174,15 +176,17 @@ pub enum CompileErrorKind {
     UnsupportedPatternExpr,
     #[error("not a valid binding")]
     UnsupportedBinding,
-    #[error("floating point numbers cannot be used in patterns")]
+    #[error("floating point numbers cannot be used in patterns, use a guard like `x if x == 1.0` instead")]
     MatchFloatInPattern,
+    #[error("a spread must be the first entry in an object")]
+    ObjectSpreadNotFirst,
     #[error("duplicate key in literal object")]
     DuplicateObjectKey { existing: Span, object: Span },
     #[error("`{item}` is not a function")]
     MissingFunction { item: Item },
     #[error("`yield` must be used in function or closure")]
     YieldOutsideFunction,
-    #[error("`await` must be used inside an async function or closure")]
+    #[error("`await` used in non-async function or closure")]
     AwaitOutsideFunction,
     #[error("instance function declared outside of `impl` block")]
     InstanceFunctionOutsideImpl,
@@ -192,6 +196,10 @@ pub enum CompileErrorKind {
     UnsupportedTupleIndex { number: ast::Number },
     #[error("break outside of loop")]
     BreakOutsideOfLoop,
+    #[error("only `loop` can break with a value")]
+    BreakValueOutsideOfLoop { loop_span: Span },
+    #[error("continue outside of loop")]
+    ContinueOutsideOfLoop,
     #[error("multiple `default` branches in select")]
     SelectMultipleDefaults,
     #[error("expected expression to be terminated by a semicolon `;`")]
@@ -202,7 +210,7 @@ pub enum CompileErrorKind {
     FnConstAsyncConflict,
     #[error("a block can't both be `async` and `const` at the same time")]
     BlockConstAsyncConflict,
-    #[error("const functions can't be generators")]
+    #[error("`yield` used in const function, which can't be a generator")]
     FnConstNotGenerator,
     #[error("unsupported closure kind")]
     ClosureKind,
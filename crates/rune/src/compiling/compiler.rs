@@ -3,7 +3,7 @@ use crate::collections::HashMap;
 use crate::compiling::{
     Assemble as _, AssembleConst as _, Assembly, CompileVisitor, Loops, Scope, ScopeGuard, Scopes,
 };
-use crate::ir::{IrBudget, IrCompiler, IrInterpreter};
+use crate::ir::{self, IrBudget, IrCompile, IrCompiler, IrEvalOutcome, IrInterpreter};
 use crate::query::{Named, Query, QueryConstFn, QueryItem, Used};
 use crate::shared::Consts;
 use crate::CompileResult;
@@ -14,6 +14,8 @@ use runestick::{
     CompileMeta, CompileMetaKind, ConstValue, Context, Inst, InstValue, Item, Label, Source, Span,
     TypeCheck,
 };
+use std::ops::Range;
+use std::rc::Rc;
 use std::sync::Arc;
 
 /// A needs hint for an expression.
@@ -40,6 +42,8 @@ pub(crate) struct Compiler<'a> {
     pub(crate) source: Arc<Source>,
     /// The current macro context.
     pub(crate) storage: &'a Storage,
+    /// The item being compiled.
+    pub(crate) item: Rc<QueryItem>,
     /// The context we are compiling for.
     pub(crate) context: &'a Context,
     /// Constants storage.
@@ -127,6 +131,22 @@ impl<'a> Compiler<'a> {
         }
     }
 
+    /// Drop the local variables at the given frame offsets, so that external
+    /// values can run their [DROP][runestick::DROP] protocol as they go out of
+    /// scope.
+    ///
+    /// Since what a variable holds isn't known until runtime, this is done if
+    /// any type in the context has a drop function.
+    pub(crate) fn locals_drop(&mut self, offsets: Range<usize>, span: Span) {
+        if !self.context.has_drop_fns() {
+            return;
+        }
+
+        for offset in offsets.rev() {
+            self.asm.push(Inst::Drop { offset }, span);
+        }
+    }
+
     /// Clean up local variables by preserving the value that is on top and
     /// popping the rest.
     ///
@@ -154,10 +174,7 @@ impl<'a> Compiler<'a> {
             match &meta.kind {
                 CompileMetaKind::UnitStruct { empty, .. } => {
                     self.asm.push_with_comment(
-                        Inst::Call {
-                            hash: empty.hash,
-                            args: 0,
-                        },
+                        Inst::UnitStruct { hash: empty.hash },
                         span,
                         meta.to_string(),
                     );
@@ -174,10 +191,7 @@ impl<'a> Compiler<'a> {
                 }
                 CompileMetaKind::UnitVariant { empty, .. } => {
                     self.asm.push_with_comment(
-                        Inst::Call {
-                            hash: empty.hash,
-                            args: 0,
-                        },
+                        Inst::UnitVariant { hash: empty.hash },
                         span,
                         meta.to_string(),
                     );
@@ -788,9 +802,16 @@ impl<'a> Compiler<'a> {
                         } = &**expr_lit
                         {
                             let span = lit_number.span();
-                            let integer = lit_number
-                                .resolve(&self.storage, &*self.source)?
-                                .as_i64(pat_lit.span(), true)?;
+                            let number = lit_number.resolve(&self.storage, &*self.source)?;
+
+                            if let ast::Number::Float(..) = number {
+                                return Err(CompileError::new(
+                                    pat_lit,
+                                    CompileErrorKind::MatchFloatInPattern,
+                                ));
+                            }
+
+                            let integer = number.as_i64(pat_lit.span(), true)?;
                             load(self, Needs::Value)?;
                             self.asm.push(Inst::EqInteger { integer }, span);
                             break;
@@ -821,9 +842,16 @@ impl<'a> Compiler<'a> {
                     }
                     ast::Lit::Number(lit_number) => {
                         let span = lit_number.span();
-                        let integer = lit_number
-                            .resolve(&self.storage, &*self.source)?
-                            .as_i64(pat_lit.span(), false)?;
+                        let number = lit_number.resolve(&self.storage, &*self.source)?;
+
+                        if let ast::Number::Float(..) = number {
+                            return Err(CompileError::new(
+                                pat_lit,
+                                CompileErrorKind::MatchFloatInPattern,
+                            ));
+                        }
+
+                        let integer = number.as_i64(pat_lit.span(), false)?;
                         load(self, Needs::Value)?;
                         self.asm.push(Inst::EqInteger { integer }, span);
                         break;
@@ -854,6 +882,7 @@ impl<'a> Compiler<'a> {
         needs: Needs,
     ) -> CompileResult<()> {
         let scope = self.scopes.pop(expected, span)?;
+        self.locals_drop(scope.local_offsets(), span);
 
         if needs.value() {
             self.locals_clean(scope.local_var_count, span);
@@ -869,6 +898,15 @@ impl<'a> Compiler<'a> {
         self.contexts.last().copied()
     }
 
+    /// Warn about all variables which went out of scope without being used.
+    pub(crate) fn warn_unused_variables(&mut self) {
+        let context = self.context();
+
+        for span in self.scopes.take_unused() {
+            self.warnings.unused_variable(self.source_id, span, context);
+        }
+    }
+
     /// Calling a constant function by id and return the resuling value.
     pub(crate) fn call_const_fn<S>(
         &mut self,
@@ -926,6 +964,61 @@ impl<'a> Compiler<'a> {
         let value = interpreter.eval_value(&query_const_fn.ir_fn.ir, Used::Used)?;
         Ok(value.into_const(spanned)?)
     }
+
+    /// Try to evaluate the given expression at compile time.
+    ///
+    /// Returns `None` if the expression is not a constant expression, or if it
+    /// uses operations which are not supported by the constant evaluator.
+    pub(crate) fn eval_const_expr(
+        &mut self,
+        expr: &ast::Expr,
+    ) -> CompileResult<Option<ConstValue>> {
+        if !expr.is_const() {
+            return Ok(None);
+        }
+
+        self.eval_const(expr)
+    }
+
+    /// Try to evaluate the given target at compile time, without checking if
+    /// it's a constant expression first.
+    pub(crate) fn eval_const<T>(&mut self, target: &T) -> CompileResult<Option<ConstValue>>
+    where
+        T: IrCompile<Output = ir::Ir> + Spanned,
+    {
+        let span = target.span();
+        let mut ir_query = self.query.as_ir_query();
+
+        let mut compiler = IrCompiler {
+            storage: self.storage.clone(),
+            source: self.source.clone(),
+            query: &mut *ir_query,
+        };
+
+        let ir = match compiler.compile(target) {
+            Ok(ir) => ir,
+            Err(..) => return Ok(None),
+        };
+
+        let mut interpreter = IrInterpreter {
+            budget: IrBudget::new(1_000_000),
+            scopes: Default::default(),
+            module: self.item.module.clone(),
+            item: self.item.item.clone(),
+            consts: self.consts.clone(),
+            query: &mut *ir_query,
+        };
+
+        match interpreter.eval(&ir, Used::Used) {
+            Ok(value) => Ok(Some(value.into_const(span)?)),
+            Err(IrEvalOutcome::NotConst(..)) => Ok(None),
+            Err(IrEvalOutcome::Error(error)) => Err(error.into()),
+            Err(IrEvalOutcome::Break(span, _)) => Err(CompileError::new(
+                span,
+                CompileErrorKind::BreakOutsideOfLoop,
+            )),
+        }
+    }
 }
 
 /// Test if the given pattern is open or not.
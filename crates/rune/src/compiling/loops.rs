@@ -1,7 +1,7 @@
 use crate::ast;
 use crate::compiling::Needs;
 use crate::{CompileError, CompileErrorKind, CompileResult, Spanned as _, Storage};
-use runestick::{Label, Source};
+use runestick::{Label, Source, Span};
 use std::cell::RefCell;
 use std::rc::Rc;
 
@@ -19,8 +19,17 @@ impl Drop for LoopGuard {
 /// Loops we are inside.
 #[derive(Clone, Copy)]
 pub(crate) struct Loop {
+    /// The span of the loop.
+    pub(crate) span: Span,
+    /// If the loop can be broken out of with a value, which is only the case
+    /// for `loop`.
+    pub(crate) break_value: bool,
     /// The optional label of the loop.
     pub(crate) label: Option<ast::Label>,
+    /// The start label of the loop, jumped to by `continue`.
+    pub(crate) continue_label: Label,
+    /// The number of variables observed at the start of each iteration.
+    pub(crate) continue_var_count: usize,
     /// The end label of the loop.
     pub(crate) break_label: Label,
     /// The number of variables observed at the start of the loop.
@@ -170,6 +170,7 @@ impl CompileBuildEntry<'_> {
 
         let mut compiler = Compiler {
             storage: self.storage,
+            item: item.clone(),
             source_id: location.source_id,
             source: source.clone(),
             context: self.context,
@@ -192,6 +193,7 @@ impl CompileBuildEntry<'_> {
 
                 let span = f.ast.span();
                 let count = f.ast.args.len();
+                let docs = compiler.query.docs_for(item.id);
                 compiler.contexts.push(span);
                 f.ast.assemble_fn(&mut compiler, false)?;
 
@@ -205,6 +207,7 @@ impl CompileBuildEntry<'_> {
                         asm,
                         f.call,
                         args,
+                        docs,
                     )?;
                 }
             }
@@ -214,6 +217,7 @@ impl CompileBuildEntry<'_> {
 
                 let span = f.ast.span();
                 let count = f.ast.args.len();
+                let docs = compiler.query.docs_for(item.id);
                 compiler.contexts.push(span);
 
                 let source = compiler.source.clone();
@@ -248,6 +252,7 @@ impl CompileBuildEntry<'_> {
                         asm,
                         f.call,
                         args,
+                        docs,
                     )?;
                 }
             }
@@ -275,6 +280,7 @@ impl CompileBuildEntry<'_> {
                         asm,
                         c.call,
                         args,
+                        Vec::new(),
                     )?;
                 }
             }
@@ -296,6 +302,7 @@ impl CompileBuildEntry<'_> {
                         asm,
                         b.call,
                         Vec::new(),
+                        Vec::new(),
                     )?;
                 }
             }
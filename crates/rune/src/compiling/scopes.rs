@@ -2,6 +2,7 @@ use crate::collections::HashMap;
 use crate::compiling::Assembly;
 use crate::{CompileError, CompileErrorKind, CompileResult, CompileVisitor};
 use runestick::{Inst, SourceId, Span};
+use std::ops::Range;
 
 /// A locally declared variable, its calculated stack offset and where it was
 /// declared in its source file.
@@ -13,6 +14,9 @@ pub struct Var {
     span: Span,
     /// Variable has been taken at the given position.
     moved_at: Option<Span>,
+    /// The variable has been declared through a pattern and has not been used
+    /// yet, so it should be reported once it goes out of scope.
+    unused: bool,
 }
 
 impl Var {
@@ -82,6 +86,11 @@ impl Scope {
         }
     }
 
+    /// The frame offsets of the variables local to this scope.
+    pub(crate) fn local_offsets(&self) -> Range<usize> {
+        self.total_var_count - self.local_var_count..self.total_var_count
+    }
+
     /// Construct a new child scope.
     fn child(&self) -> Self {
         Self {
@@ -100,6 +109,7 @@ impl Scope {
             offset,
             span,
             moved_at: None,
+            unused: false,
         };
 
         self.total_var_count += 1;
@@ -130,6 +140,7 @@ impl Scope {
                 offset,
                 span,
                 moved_at: None,
+                unused: !name.starts_with('_'),
             },
         );
 
@@ -171,8 +182,8 @@ impl Scope {
     }
 
     /// Access the variable with the given name.
-    fn get(&self, name: &str, span: Span) -> CompileResult<Option<&Var>> {
-        if let Some(var) = self.locals.get(name) {
+    fn get(&mut self, name: &str, span: Span) -> CompileResult<Option<&Var>> {
+        if let Some(var) = self.locals.get_mut(name) {
             if let Some(moved_at) = var.moved_at {
                 return Err(CompileError::new(
                     span,
@@ -180,6 +191,7 @@ impl Scope {
                 ));
             }
 
+            var.unused = false;
             return Ok(Some(var));
         }
 
@@ -197,6 +209,7 @@ impl Scope {
             }
 
             var.moved_at = Some(span);
+            var.unused = false;
             return Ok(Some(var));
         }
 
@@ -213,6 +226,8 @@ pub(crate) struct ScopeGuard(usize);
 
 pub(crate) struct Scopes {
     scopes: Vec<Scope>,
+    /// Spans of variables which went out of scope without being used.
+    unused: Vec<Span>,
 }
 
 impl Scopes {
@@ -220,13 +235,14 @@ impl Scopes {
     pub(crate) fn new() -> Self {
         Self {
             scopes: vec![Scope::new()],
+            unused: Vec::new(),
         }
     }
 
     /// Try to get the local with the given name. Returns `None` if it's
     /// missing.
     pub(crate) fn try_get_var(
-        &self,
+        &mut self,
         name: &str,
         source_id: SourceId,
         visitor: &mut dyn CompileVisitor,
@@ -234,7 +250,7 @@ impl Scopes {
     ) -> CompileResult<Option<&Var>> {
         log::trace!("get var: {}", name);
 
-        for scope in self.scopes.iter().rev() {
+        for scope in self.scopes.iter_mut().rev() {
             if let Some(var) = scope.get(name, span)? {
                 log::trace!("found var: {} => {:?}", name, var);
                 visitor.visit_variable_use(source_id, var, span);
@@ -269,7 +285,7 @@ impl Scopes {
 
     /// Get the local with the given name.
     pub(crate) fn get_var(
-        &self,
+        &mut self,
         name: &str,
         source_id: SourceId,
         visitor: &mut dyn CompileVisitor,
@@ -357,9 +373,25 @@ impl Scopes {
             .pop()
             .ok_or_else(|| CompileError::msg(&span, "missing parent scope"))?;
 
+        self.unused.extend(
+            scope
+                .locals
+                .values()
+                .filter(|var| var.unused)
+                .map(|var| var.span),
+        );
+
         Ok(scope)
     }
 
+    /// Take the spans of all variables which went out of scope without being
+    /// used.
+    pub(crate) fn take_unused(&mut self) -> Vec<Span> {
+        let mut unused = std::mem::take(&mut self.unused);
+        unused.sort_by_key(|span| span.start);
+        unused
+    }
+
     /// Construct a new child scope and return its guard.
     pub(crate) fn push_child(&mut self, span: Span) -> CompileResult<ScopeGuard> {
         let scope = self.last(span)?.child();
@@ -262,7 +262,7 @@ impl UnitBuilder {
         let current = current
             .into_iter()
             .map(|s| s.as_ref().to_owned())
-            .collect::<Box<_>>();
+            .collect::<Box<[String]>>();
 
         let hash = Hash::object_keys(&current[..]);
 
@@ -308,6 +308,7 @@ impl UnitBuilder {
                 let signature = DebugSignature {
                     path: meta.item.clone(),
                     args: DebugArgs::EmptyArgs,
+                    docs: meta.docs.clone(),
                 };
 
                 let rtti = Arc::new(Rtti {
@@ -350,6 +351,7 @@ impl UnitBuilder {
                 let signature = DebugSignature {
                     path: meta.item.clone(),
                     args: DebugArgs::TupleArgs(tuple.args),
+                    docs: meta.docs.clone(),
                 };
 
                 let rtti = Arc::new(Rtti {
@@ -426,6 +428,7 @@ impl UnitBuilder {
                 let signature = DebugSignature {
                     path: meta.item.clone(),
                     args: DebugArgs::EmptyArgs,
+                    docs: meta.docs.clone(),
                 };
 
                 if inner.functions.insert(empty.hash, info).is_some() {
@@ -473,6 +476,7 @@ impl UnitBuilder {
                 let signature = DebugSignature {
                     path: meta.item.clone(),
                     args: DebugArgs::TupleArgs(tuple.args),
+                    docs: meta.docs.clone(),
                 };
 
                 if inner.functions.insert(tuple.hash, info).is_some() {
@@ -562,6 +566,7 @@ impl UnitBuilder {
         assembly: Assembly,
         call: Call,
         debug_args: Vec<String>,
+        docs: Vec<String>,
     ) -> Result<(), CompileError> {
         let mut inner = self.inner.borrow_mut();
 
@@ -570,7 +575,7 @@ impl UnitBuilder {
 
         inner.functions_rev.insert(offset, hash);
         let info = UnitFn::Offset { offset, call, args };
-        let signature = DebugSignature::new(path, debug_args);
+        let signature = DebugSignature::new(path, debug_args, docs);
 
         if inner.functions.insert(hash, info).is_some() {
             return Err(CompileError::new(
@@ -618,6 +623,7 @@ impl UnitBuilder {
         assembly: Assembly,
         call: Call,
         debug_args: Vec<String>,
+        docs: Vec<String>,
     ) -> Result<(), CompileError> {
         log::trace!("instance fn: {}", path);
 
@@ -628,7 +634,7 @@ impl UnitBuilder {
         let hash = Hash::type_hash(&path);
 
         let info = UnitFn::Offset { offset, call, args };
-        let signature = DebugSignature::new(path, debug_args);
+        let signature = DebugSignature::new(path, debug_args, docs);
 
         if inner.functions.insert(instance_fn, info).is_some() {
             return Err(CompileError::new(
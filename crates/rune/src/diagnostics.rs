@@ -139,6 +139,43 @@ impl EmitDiagnostics for Warnings {
                             .with_message("unnecessary semicolon"),
                     );
 
+                    None
+                }
+                WarningKind::AssignToTemporary { span, context } => {
+                    labels.push(
+                        Label::primary(w.source_id, span.range())
+                            .with_message("assigning through a temporary value"),
+                    );
+
+                    notes.push(String::from(
+                        "Hint: Store the value in a variable before assigning to it",
+                    ));
+
+                    *context
+                }
+                WarningKind::UnusedVariable { span, context } => {
+                    labels.push(
+                        Label::primary(w.source_id, span.range())
+                            .with_message("variable is never used"),
+                    );
+
+                    notes.push(String::from(
+                        "Hint: Prefix the variable with an underscore, like `_name`, if this is intentional",
+                    ));
+
+                    *context
+                }
+                WarningKind::Unreachable { span, cause } => {
+                    labels.push(
+                        Label::primary(w.source_id, span.range())
+                            .with_message("unreachable statement"),
+                    );
+
+                    labels.push(
+                        Label::secondary(w.source_id, cause.range())
+                            .with_message("any code following this is unreachable"),
+                    );
+
                     None
                 }
             };
@@ -216,6 +253,12 @@ impl EmitDiagnostics for VmError {
 
         labels.push(Label::primary(source_id, span.range()).with_message(error.to_string()));
 
+        for frame in self.backtrace().into_iter().skip(1) {
+            if let (Some(source_id), Some(span)) = (frame.source_id, frame.span) {
+                labels.push(Label::secondary(source_id, span.range()).with_message("called here"));
+            }
+        }
+
         let diagnostic = Diagnostic::error()
             .with_message("virtual machine error")
             .with_labels(labels);
@@ -361,6 +404,12 @@ impl EmitDiagnostics for Error {
                         notes.push(note);
                     }
                 }
+                CompileErrorKind::BreakValueOutsideOfLoop { loop_span } => {
+                    labels.push(
+                        Label::secondary(this.source_id(), loop_span.range())
+                            .with_message("in this loop, which can't break with a value"),
+                    );
+                }
                 CompileErrorKind::VariableMoved { moved_at, .. } => {
                     labels.push(
                         Label::secondary(this.source_id(), moved_at.range())
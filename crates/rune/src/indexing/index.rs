@@ -58,6 +58,35 @@ pub(crate) struct Indexer<'a> {
 }
 
 impl<'a> Indexer<'a> {
+    /// Collect the doc strings from the given attributes, erroring with the
+    /// given message if there are any other attributes.
+    fn docs(
+        &self,
+        attributes: &[ast::Attribute],
+        unsupported: &'static str,
+    ) -> CompileResult<Vec<String>> {
+        let mut attributes = attrs::Attributes::new(
+            attributes.to_vec(),
+            self.storage.clone(),
+            self.source.clone(),
+        );
+
+        let docs = attributes.try_parse_all::<attrs::Doc>()?;
+
+        if let Some(span) = attributes.remaining() {
+            return Err(CompileError::msg(span, unsupported));
+        }
+
+        let mut out = Vec::with_capacity(docs.len());
+
+        for doc in docs {
+            let doc = doc.doc_string.resolve(&self.storage, &*self.source)?;
+            out.push(doc.into_owned());
+        }
+
+        Ok(out)
+    }
+
     /// Try to expand an internal macro.
     fn try_expand_internal_macro(
         &mut self,
@@ -101,7 +130,7 @@ impl<'a> Indexer<'a> {
 
         match &mut internal_macro {
             BuiltInMacro::Template(template) => {
-                for expr in &mut template.exprs {
+                for (expr, _) in &mut template.exprs {
                     expr.index(self)?;
                 }
             }
@@ -125,7 +154,31 @@ impl<'a> Indexer<'a> {
         let mut exprs = Vec::new();
 
         while !p.is_eof()? {
-            exprs.push(p.parse::<ast::Expr>()?);
+            let expr = p.parse::<ast::Expr>()?;
+
+            let format_type = if p.parse::<Option<T![:]>>()?.is_some() {
+                let ident = p.parse::<ast::Ident>()?;
+                let spec = ident.resolve(&self.storage, &self.source)?;
+
+                let format_type = match spec.as_ref() {
+                    "x" => format::Type::LowerHex,
+                    "X" => format::Type::UpperHex,
+                    "o" => format::Type::Octal,
+                    "b" => format::Type::Binary,
+                    _ => {
+                        return Err(ParseError::unsupported(
+                            ident.span(),
+                            "template format type",
+                        ));
+                    }
+                };
+
+                Some((ident, format_type))
+            } else {
+                None
+            };
+
+            exprs.push((expr, format_type));
 
             if p.parse::<Option<T![,]>>()?.is_none() {
                 break;
@@ -554,12 +607,7 @@ impl Index for ast::ItemFn {
         let span = self.span();
         log::trace!("ItemFn => {:?}", idx.source.source(span));
 
-        if let Some(first) = self.attributes.first() {
-            return Err(CompileError::msg(
-                first,
-                "function attributes are not supported",
-            ));
-        }
+        let docs = idx.docs(&self.attributes, "function attributes are not supported")?;
 
         let is_toplevel = idx.items.is_empty();
         let name = self.name.resolve(&idx.storage, &*idx.source)?;
@@ -574,6 +622,8 @@ impl Index for ast::ItemFn {
             visibility,
         )?;
 
+        idx.query.insert_docs(item.id, docs.clone());
+
         let kind = match (self.const_token, self.async_token) {
             (Some(const_token), Some(async_token)) => {
                 return Err(CompileError::new(
@@ -619,13 +669,6 @@ impl Index for ast::ItemFn {
             Some(call) => call,
             // const function.
             None => {
-                if f.generator {
-                    return Err(CompileError::new(
-                        span,
-                        CompileErrorKind::FnConstNotGenerator,
-                    ));
-                }
-
                 idx.query
                     .index_const_fn(&item, &idx.source, Box::new(self.clone()))?;
 
@@ -671,6 +714,7 @@ impl Index for ast::ItemFn {
                     path: idx.source.path().map(ToOwned::to_owned),
                     source_id: idx.source_id,
                 }),
+                docs,
             };
 
             idx.query.insert_meta(span, meta)?;
@@ -694,6 +738,7 @@ impl Index for ast::ItemFn {
                     path: idx.source.path().map(ToOwned::to_owned),
                     source_id: idx.source_id,
                 }),
+                docs,
             };
 
             idx.query.insert_meta(span, meta)?;
@@ -1013,6 +1058,9 @@ impl Index for ast::Expr {
             ast::Expr::Binary(expr_binary) => {
                 expr_binary.index(idx)?;
             }
+            ast::Expr::Range(expr_range) => {
+                expr_range.index(idx)?;
+            }
             ast::Expr::Match(expr_if) => {
                 expr_if.index(idx)?;
             }
@@ -1043,6 +1091,9 @@ impl Index for ast::Expr {
             ast::Expr::Break(expr_break) => {
                 expr_break.index(idx)?;
             }
+            ast::Expr::Continue(expr_continue) => {
+                expr_continue.index(idx)?;
+            }
             ast::Expr::Yield(expr_yield) => {
                 expr_yield.index(idx)?;
             }
@@ -1151,6 +1202,17 @@ impl Index for ast::ExprBinary {
     }
 }
 
+impl Index for ast::ExprRange {
+    fn index(&mut self, idx: &mut Indexer<'_>) -> CompileResult<()> {
+        let span = self.span();
+        log::trace!("ExprRange => {:?}", idx.source.source(span));
+
+        self.from.index(idx)?;
+        self.to.index(idx)?;
+        Ok(())
+    }
+}
+
 impl Index for ast::ExprMatch {
     fn index(&mut self, idx: &mut Indexer<'_>) -> CompileResult<()> {
         let span = self.span();
@@ -1194,12 +1256,7 @@ impl Index for ast::ItemEnum {
     fn index(&mut self, idx: &mut Indexer<'_>) -> CompileResult<()> {
         let span = self.span();
 
-        if let Some(first) = self.attributes.first() {
-            return Err(CompileError::msg(
-                first,
-                "enum attributes are not supported",
-            ));
-        }
+        let docs = idx.docs(&self.attributes, "enum attributes are not supported")?;
 
         let name = self.name.resolve(&idx.storage, &*idx.source)?;
         let _guard = idx.items.push_name(name.as_ref());
@@ -1213,23 +1270,17 @@ impl Index for ast::ItemEnum {
             visibility,
         )?;
 
+        idx.query.insert_docs(enum_item.id, docs);
         idx.query.index_enum(&enum_item, &idx.source)?;
 
         for (variant, _) in &mut self.variants {
-            if let Some(first) = variant.attributes.first() {
-                return Err(CompileError::msg(
-                    first,
-                    "variant attributes are not supported yet",
-                ));
-            }
+            let docs = idx.docs(
+                &variant.attributes,
+                "variant attributes are not supported yet",
+            )?;
 
             for (field, _) in variant.body.fields() {
-                if let Some(first) = field.attributes.first() {
-                    return Err(CompileError::msg(
-                        first,
-                        "field attributes are not supported",
-                    ));
-                }
+                idx.docs(&field.attributes, "field attributes are not supported")?;
             }
 
             let span = variant.name.span();
@@ -1244,6 +1295,7 @@ impl Index for ast::ItemEnum {
                 Visibility::Public,
             )?;
             variant.id = Some(item.id);
+            idx.query.insert_docs(item.id, docs);
 
             idx.query
                 .index_variant(&item, &idx.source, enum_item.id, variant.clone())?;
@@ -1257,20 +1309,12 @@ impl Index for Box<ast::ItemStruct> {
     fn index(&mut self, idx: &mut Indexer<'_>) -> CompileResult<()> {
         let span = self.span();
 
-        if let Some(first) = self.attributes.first() {
-            return Err(CompileError::msg(
-                first,
-                "struct attributes are not supported",
-            ));
-        }
+        let docs = idx.docs(&self.attributes, "struct attributes are not supported")?;
 
         for (field, _) in self.body.fields() {
-            if let Some(first) = field.attributes.first() {
-                return Err(CompileError::msg(
-                    first,
-                    "field attributes are not supported",
-                ));
-            } else if !field.visibility.is_inherited() {
+            idx.docs(&field.attributes, "field attributes are not supported")?;
+
+            if !field.visibility.is_inherited() {
                 return Err(CompileError::msg(
                     &field,
                     "field visibility levels are not supported",
@@ -1291,6 +1335,7 @@ impl Index for Box<ast::ItemStruct> {
         )?;
         self.id = Some(item.id);
 
+        idx.query.insert_docs(item.id, docs);
         idx.query.index_struct(&item, &idx.source, self.clone())?;
         Ok(())
     }
@@ -1298,12 +1343,7 @@ impl Index for Box<ast::ItemStruct> {
 
 impl Index for ast::ItemImpl {
     fn index(&mut self, idx: &mut Indexer<'_>) -> CompileResult<()> {
-        if let Some(first) = self.attributes.first() {
-            return Err(CompileError::msg(
-                first,
-                "impl attributes are not supported",
-            ));
-        }
+        idx.docs(&self.attributes, "impl attributes are not supported")?;
 
         let mut guards = Vec::new();
 
@@ -1336,12 +1376,7 @@ impl Index for ast::ItemImpl {
 
 impl Index for ast::ItemMod {
     fn index(&mut self, idx: &mut Indexer<'_>) -> CompileResult<()> {
-        if let Some(first) = self.attributes.first() {
-            return Err(CompileError::msg(
-                first,
-                "module attributes are not supported",
-            ));
-        }
+        idx.docs(&self.attributes, "module attributes are not supported")?;
 
         let name_span = self.name_span();
 
@@ -1375,12 +1410,10 @@ impl Index for ast::ItemMod {
 
 impl Index for Box<ast::ItemConst> {
     fn index(&mut self, idx: &mut Indexer<'_>) -> CompileResult<()> {
-        if let Some(first) = self.attributes.first() {
-            return Err(CompileError::msg(
-                first,
-                "attributes on constants are not supported",
-            ));
-        }
+        let docs = idx.docs(
+            &self.attributes,
+            "attributes on constants are not supported",
+        )?;
 
         let span = self.span();
         let name = self.name.resolve(&idx.storage, &*idx.source)?;
@@ -1395,6 +1428,7 @@ impl Index for Box<ast::ItemConst> {
         )?;
 
         self.id = Some(item.id);
+        idx.query.insert_docs(item.id, docs);
 
         self.expr.index(idx)?;
 
@@ -1414,6 +1448,9 @@ impl Index for ast::Item {
             idx.source.clone(),
         );
 
+        // NB: doc strings are collected by the specific items.
+        attributes.try_parse_all::<attrs::Doc>()?;
+
         match self {
             ast::Item::Enum(item_enum) => {
                 item_enum.index(idx)?;
@@ -1515,7 +1552,7 @@ impl Index for ast::ExprFor {
         self.iter.index(idx)?;
 
         let _guard = idx.scopes.push_scope();
-        self.var.index(idx)?;
+        self.binding.index(idx)?;
         self.body.index(idx)?;
         Ok(())
     }
@@ -1610,6 +1647,14 @@ impl Index for ast::ExprIndex {
     }
 }
 
+impl Index for ast::ExprContinue {
+    fn index(&mut self, idx: &mut Indexer<'_>) -> CompileResult<()> {
+        let span = self.span();
+        log::trace!("ExprContinue => {:?}", idx.source.source(span));
+        Ok(())
+    }
+}
+
 impl Index for ast::ExprBreak {
     fn index(&mut self, idx: &mut Indexer<'_>) -> CompileResult<()> {
         let span = self.span();
@@ -1699,6 +1744,10 @@ impl Index for ast::ExprSelect {
                     pat.body.index(idx)?;
                 }
                 ast::ExprSelectBranch::Default(def) => {
+                    if let Some(timeout) = &mut def.timeout {
+                        timeout.duration.index(idx)?;
+                    }
+
                     default_branch = Some(def);
                 }
             }
@@ -1791,9 +1840,16 @@ impl Index for ast::ExprObject {
             ast::ObjectIdent::Anonymous(..) => (),
         }
 
-        for (assign, _) in &mut self.assignments {
-            if let Some((_, expr)) = &mut assign.assign {
-                expr.index(idx)?;
+        for (field, _) in &mut self.assignments {
+            match field {
+                ast::ObjectField::Assign(assign) => {
+                    if let Some((_, expr)) = &mut assign.assign {
+                        expr.index(idx)?;
+                    }
+                }
+                ast::ObjectField::Spread(spread) => {
+                    spread.expr.index(idx)?;
+                }
             }
         }
 
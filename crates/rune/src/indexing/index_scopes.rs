@@ -250,6 +250,9 @@ impl IndexScopes {
 
     /// Mark that a yield was used, meaning the encapsulating function is a
     /// generator.
+    ///
+    /// Const functions are evaluated at compile time and can't be generators,
+    /// so using `yield` in one is an error.
     pub(crate) fn mark_yield(&mut self, span: Span) -> Result<(), CompileError> {
         let mut levels = self.levels.borrow_mut();
         let iter = levels.iter_mut().rev();
@@ -257,6 +260,13 @@ impl IndexScopes {
         for level in iter {
             match level {
                 IndexScopeLevel::IndexFunction(fun) => {
+                    if let IndexFnKind::Const = fun.kind {
+                        return Err(CompileError::new(
+                            span,
+                            CompileErrorKind::FnConstNotGenerator,
+                        ));
+                    }
+
                     fun.generator = true;
                     return Ok(());
                 }
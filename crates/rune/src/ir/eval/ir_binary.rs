@@ -8,7 +8,8 @@ impl IrEval for ir::IrBinary {
         interp: &mut IrInterpreter<'_>,
         used: Used,
     ) -> Result<Self::Output, IrEvalOutcome> {
-        use std::ops::{Add, Mul, Shl, Shr, Sub};
+        use num::Zero as _;
+        use std::ops::{Add, Mul, Rem, Shl, Shr, Sub};
 
         let span = self.span();
         interp.budget.take(span)?;
@@ -33,6 +34,13 @@ impl IrEval for ir::IrBinary {
                         .ok_or_else(|| IrError::msg(span, "division by zero"))?;
                     return Ok(IrValue::Integer(number));
                 }
+                ir::IrBinaryOp::Rem => {
+                    if b.is_zero() {
+                        return Err(IrEvalOutcome::from(IrError::msg(span, "remainder by zero")));
+                    }
+
+                    return Ok(IrValue::Integer(a.rem(&b)));
+                }
                 ir::IrBinaryOp::Shl => {
                     let b = u32::try_from(b).map_err(|_| {
                         IrError::msg(&self.rhs, "cannot be converted to shift operand")
@@ -62,6 +70,7 @@ impl IrEval for ir::IrBinary {
                     ir::IrBinaryOp::Sub => return Ok(IrValue::Float(a - b)),
                     ir::IrBinaryOp::Mul => return Ok(IrValue::Float(a * b)),
                     ir::IrBinaryOp::Div => return Ok(IrValue::Float(a / b)),
+                    ir::IrBinaryOp::Rem => return Ok(IrValue::Float(a % b)),
                     ir::IrBinaryOp::Lt => return Ok(IrValue::Bool(a < b)),
                     ir::IrBinaryOp::Lte => return Ok(IrValue::Bool(a <= b)),
                     ir::IrBinaryOp::Eq => return Ok(IrValue::Bool(a == b)),
@@ -235,6 +235,7 @@ impl IrCompile for ast::ExprBinary {
             ast::BinOp::Sub => ir::IrBinaryOp::Sub,
             ast::BinOp::Mul => ir::IrBinaryOp::Mul,
             ast::BinOp::Div => ir::IrBinaryOp::Div,
+            ast::BinOp::Rem => ir::IrBinaryOp::Rem,
             ast::BinOp::Shl => ir::IrBinaryOp::Shl,
             ast::BinOp::Shr => ir::IrBinaryOp::Shr,
             ast::BinOp::Lt => ir::IrBinaryOp::Lt,
@@ -349,7 +350,14 @@ impl IrCompile for ast::ExprObject {
     fn compile(&self, c: &mut IrCompiler<'_>) -> Result<Self::Output, IrError> {
         let mut assignments = Vec::new();
 
-        for (assign, _) in &self.assignments {
+        for (field, _) in &self.assignments {
+            let assign = match field {
+                ast::ObjectField::Assign(assign) => assign,
+                ast::ObjectField::Spread(spread) => {
+                    return Err(IrError::msg(spread, "spread not supported yet"));
+                }
+            };
+
             let key = c.resolve(&assign.key)?.into_owned();
 
             let ir = if let Some((_, expr)) = &assign.assign {
@@ -459,7 +467,14 @@ impl IrCompile for BuiltInTemplate {
         let span = self.span;
         let mut components = Vec::new();
 
-        for expr in &self.exprs {
+        for (expr, format_type) in &self.exprs {
+            if let Some((ident, _)) = format_type {
+                return Err(IrError::msg(
+                    ident,
+                    "format types in constant templates are not supported",
+                ));
+            }
+
             if let ast::Expr::Lit(expr_lit) = expr {
                 if let ast::ExprLit {
                     lit: ast::Lit::Str(s),
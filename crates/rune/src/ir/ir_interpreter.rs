@@ -4,7 +4,7 @@ use crate::ir::{IrQuery, IrValue};
 use crate::query::{QueryMod, Used};
 use crate::shared::Consts;
 use crate::{IrError, IrErrorKind, Spanned};
-use runestick::{CompileMetaKind, ConstValue, Item, Span};
+use runestick::{CompileMeta, CompileMetaKind, ConstValue, Item, Span};
 use std::rc::Rc;
 
 /// Ir Scopes.
@@ -118,6 +118,23 @@ impl IrInterpreter<'_> {
                     CompileMetaKind::Const { const_value, .. } => {
                         return Ok(IrValue::from_const(spanned, const_value.clone())?);
                     }
+                    // NB: imported constants might either come from the
+                    // context, or be declared elsewhere in the unit.
+                    CompileMetaKind::Import { imported } => {
+                        if let Some(const_value) = self.consts.get(imported) {
+                            return Ok(IrValue::from_const(spanned, const_value)?);
+                        }
+
+                        if let Some(CompileMeta {
+                            kind: CompileMetaKind::Const { const_value },
+                            ..
+                        }) = self.query.query_meta(spanned, imported, used)?
+                        {
+                            return Ok(IrValue::from_const(spanned, const_value)?);
+                        }
+
+                        return Err(IrError::new(spanned, IrErrorKind::UnsupportedMeta { meta }));
+                    }
                     _ => {
                         return Err(IrError::new(spanned, IrErrorKind::UnsupportedMeta { meta }));
                     }
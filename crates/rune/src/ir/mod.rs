@@ -350,6 +350,8 @@ pub enum IrBinaryOp {
     Mul,
     /// Division `/`.
     Div,
+    /// Remainder `%`.
+    Rem,
     /// `<<`.
     Shl,
     /// `>>`.
@@ -190,6 +190,7 @@ pub mod macros;
 mod options;
 mod parsing;
 mod query;
+mod repl;
 mod shared;
 mod spanned;
 mod worker;
@@ -223,6 +224,7 @@ pub use self::parsing::{
     ResolveErrorKind, ResolveOwned,
 };
 pub use self::query::{QueryError, QueryErrorKind, Used};
+pub use self::repl::{Repl, ReplError};
 pub use self::shared::{Location, ScopeError, ScopeErrorKind};
 pub use self::spanned::{OptionSpanned, Spanned};
 pub use compiling::compile;
@@ -21,6 +21,9 @@ impl Warning {
             WarningKind::TemplateWithoutExpansions { span, .. } => *span,
             WarningKind::RemoveTupleCallParams { span, .. } => *span,
             WarningKind::UnecessarySemiColon { span, .. } => *span,
+            WarningKind::AssignToTemporary { span, .. } => *span,
+            WarningKind::UnusedVariable { span, .. } => *span,
+            WarningKind::Unreachable { span, .. } => *span,
         }
     }
 }
@@ -81,4 +84,29 @@ pub enum WarningKind {
         /// Span where the semi-colon is.
         span: Span,
     },
+    /// Assigning through a temporary value, which means the assignment is
+    /// lost.
+    #[error("assignment to a temporary value is lost")]
+    AssignToTemporary {
+        /// The span of the temporary value.
+        span: Span,
+        /// The context in which it is used.
+        context: Option<Span>,
+    },
+    /// A local variable is declared but never used.
+    #[error("unused variable")]
+    UnusedVariable {
+        /// The span where the variable is declared.
+        span: Span,
+        /// The context in which it is declared.
+        context: Option<Span>,
+    },
+    /// A statement can never be reached.
+    #[error("unreachable statement")]
+    Unreachable {
+        /// The span of the unreachable statement.
+        span: Span,
+        /// The span of the statement which causes it to be unreachable.
+        cause: Span,
+    },
 }
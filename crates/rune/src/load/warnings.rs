@@ -126,6 +126,43 @@ impl Warnings {
         }
     }
 
+    /// Add a warning about assigning through a temporary value.
+    ///
+    /// Like `(1, 2).0 = 5`.
+    pub fn assign_to_temporary(&mut self, source_id: usize, span: Span, context: Option<Span>) {
+        if let Some(w) = &mut self.warnings {
+            w.push(Warning {
+                source_id,
+                kind: WarningKind::AssignToTemporary { span, context },
+            });
+        }
+    }
+
+    /// Add a warning about a local variable which is never used.
+    ///
+    /// Like `let unused = 1;`.
+    pub fn unused_variable(&mut self, source_id: usize, span: Span, context: Option<Span>) {
+        if let Some(w) = &mut self.warnings {
+            w.push(Warning {
+                source_id,
+                kind: WarningKind::UnusedVariable { span, context },
+            });
+        }
+    }
+
+    /// Add a warning about a statement which can never be reached, because
+    /// the statement preceding it unconditionally diverges.
+    ///
+    /// Like `return 1; foo();`.
+    pub fn unreachable(&mut self, source_id: usize, span: Span, cause: Span) {
+        if let Some(w) = &mut self.warnings {
+            w.push(Warning {
+                source_id,
+                kind: WarningKind::Unreachable { span, cause },
+            });
+        }
+    }
+
     /// Add a warning about an unecessary semi-colon.
     pub fn uneccessary_semi_colon(&mut self, source_id: usize, span: Span) {
         if let Some(w) = &mut self.warnings {
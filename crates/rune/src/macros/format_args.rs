@@ -488,6 +488,10 @@ fn expand_format_spec<'a>(
                             format_type = Some(format::Type::Binary);
                             iter.next();
                         }
+                        'o' => {
+                            format_type = Some(format::Type::Octal);
+                            iter.next();
+                        }
                         'p' => {
                             format_type = Some(format::Type::Pointer);
                             iter.next();
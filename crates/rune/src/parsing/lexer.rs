@@ -88,6 +88,53 @@ impl<'a> Lexer<'a> {
         });
     }
 
+    /// Emit a doc comment as a `#[doc = "..."]` attribute, where the string
+    /// is the rest of the line following `///`.
+    fn emit_doc_attribute(&mut self, start: usize) {
+        let text_start = self.iter.pos();
+
+        while !matches!(self.iter.peek(), Some('\n') | None) {
+            self.iter.next();
+        }
+
+        let (text, text_span) = self.iter.source_from(text_start);
+
+        let text_span = if text.ends_with('\r') {
+            text_span.trim_end(1)
+        } else {
+            text_span
+        };
+
+        let span = self.iter.span_from(start);
+
+        self.buffer.push_back(ast::Token { kind: K![#], span });
+
+        self.buffer.push_back(ast::Token {
+            kind: K!['['],
+            span,
+        });
+
+        self.buffer.push_back(ast::Token {
+            kind: ast::Kind::Ident(ast::StringSource::BuiltIn(ast::BuiltIn::Doc)),
+            span,
+        });
+
+        self.buffer.push_back(ast::Token { kind: K![=], span });
+
+        self.buffer.push_back(ast::Token {
+            kind: ast::Kind::Str(ast::StrSource::Text(ast::StrText {
+                escaped: false,
+                wrapped: false,
+            })),
+            span: text_span,
+        });
+
+        self.buffer.push_back(ast::Token {
+            kind: K![']'],
+            span,
+        });
+    }
+
     fn next_ident(&mut self, start: usize) -> Result<Option<ast::Token>, ParseError> {
         while let Some(c) = self.iter.peek() {
             if !matches!(c, 'a'..='z' | 'A'..='Z' | '_' | '0'..='9') {
@@ -134,7 +181,8 @@ impl<'a> Lexer<'a> {
                 c if char::is_alphanumeric(c) => {
                     self.iter.next();
                 }
-                '.' if !is_fractional => {
+                // NB: a dot followed by another dot is a range, like `0..10`.
+                '.' if !is_fractional && self.iter.peek2() != Some('.') => {
                     self.iter.next();
                     is_fractional = true;
 
@@ -383,6 +431,21 @@ impl<'a> Lexer<'a> {
 
                     escaped = true;
                 }
+                '{' | '}' => {
+                    self.iter.next();
+
+                    // NB: literal braces have to be escaped by doubling them,
+                    // like `{{` and `}}`.
+                    if self.iter.peek() != Some(c) {
+                        return Err(ParseError::new(
+                            self.iter.span_from(s),
+                            ParseErrorKind::UnescapedTemplateBrace { c },
+                        ));
+                    }
+
+                    self.iter.next();
+                    escaped = true;
+                }
                 '`' => {
                     let span = self.iter.span_from(start);
                     let had_string = start != self.iter.pos();
@@ -480,6 +543,10 @@ impl<'a> Lexer<'a> {
                             self.iter.next();
                             break ast::Kind::StarEq;
                         }
+                        ('*', '*') => {
+                            self.iter.next();
+                            break ast::Kind::StarStar;
+                        }
                         ('/', '=') => {
                             self.iter.next();
                             break ast::Kind::SlashEq;
@@ -501,6 +568,19 @@ impl<'a> Lexer<'a> {
                             break ast::Kind::PipeEq;
                         }
                         ('/', '/') => {
+                            self.iter.next();
+
+                            // NB: like in Rust, `///` is a doc comment but
+                            // `////` is a regular comment.
+                            if matches!(self.iter.peek(), Some('/')) {
+                                self.iter.next();
+
+                                if !matches!(self.iter.peek(), Some('/')) {
+                                    self.emit_doc_attribute(start);
+                                    continue 'outer;
+                                }
+                            }
+
                             self.consume_line();
                             continue 'outer;
                         }
@@ -554,7 +634,13 @@ impl<'a> Lexer<'a> {
                         }
                         ('.', '.') => {
                             self.iter.next();
-                            break ast::Kind::DotDot;
+
+                            break if matches!(self.iter.peek(), Some('=')) {
+                                self.iter.next();
+                                ast::Kind::DotDotEq
+                            } else {
+                                ast::Kind::DotDot
+                            };
                         }
                         ('=', '>') => {
                             self.iter.next();
@@ -569,6 +655,9 @@ impl<'a> Lexer<'a> {
                             self.iter.next();
                             return self.next_lit_byte(start);
                         }
+                        ('_', 'a'..='z' | 'A'..='Z' | '_' | '0'..='9') => {
+                            return self.next_ident(start);
+                        }
                         ('b', '"') => {
                             self.iter.next();
                             return self.next_str(
@@ -728,6 +817,13 @@ impl<'a> SourceIter<'a> {
         self.chars.clone().next()
     }
 
+    /// Peek the character after the next one.
+    fn peek2(&self) -> Option<char> {
+        let mut chars = self.chars.clone();
+        chars.next()?;
+        chars.next()
+    }
+
     /// Peek the next character with position.
     fn peek_with_pos(&self) -> Option<(usize, char)> {
         self.clone().next_with_pos()
@@ -1248,4 +1344,42 @@ mod tests {
             },
         };
     }
+
+    #[test]
+    fn test_doc_comments() {
+        test_lexer! {
+            "/// Hello\r\n//// Regular\nfn",
+            ast::Token {
+                span: span!(0, 10),
+                kind: K![#],
+            },
+            ast::Token {
+                span: span!(0, 10),
+                kind: K!['['],
+            },
+            ast::Token {
+                span: span!(0, 10),
+                kind: ast::Kind::Ident(ast::StringSource::BuiltIn(ast::BuiltIn::Doc)),
+            },
+            ast::Token {
+                span: span!(0, 10),
+                kind: K![=],
+            },
+            ast::Token {
+                span: span!(3, 9),
+                kind: ast::Kind::Str(ast::StrSource::Text(ast::StrText {
+                    escaped: false,
+                    wrapped: false,
+                })),
+            },
+            ast::Token {
+                span: span!(0, 10),
+                kind: K![']'],
+            },
+            ast::Token {
+                span: span!(24, 26),
+                kind: ast::Kind::Fn,
+            },
+        };
+    }
 }
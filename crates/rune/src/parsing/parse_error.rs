@@ -88,6 +88,8 @@ pub enum ParseErrorKind {
     ExpectedByteClose,
     #[error("unexpected character `{c}`")]
     UnexpectedChar { c: char },
+    #[error("unescaped `{c}` in template string, use `{c}{c}` to escape it")]
+    UnescapedTemplateBrace { c: char },
     #[error("group required in expression to determine precedence")]
     PrecedenceGroupRequired,
     #[error("number literal out of bounds `-9223372036854775808` to `9223372036854775807`")]
@@ -39,8 +39,9 @@ pub(crate) struct BuiltInTemplate {
     pub(crate) span: Span,
     /// Indicate if template originated from literal.
     pub(crate) from_literal: bool,
-    /// Expressions being concatenated as a template.
-    pub(crate) exprs: Vec<ast::Expr>,
+    /// Expressions being concatenated as a template, each with an optional
+    /// format type, as in `${value:x}`.
+    pub(crate) exprs: Vec<(ast::Expr, Option<(ast::Ident, format::Type)>)>,
 }
 
 /// An internal format specification.
@@ -116,6 +117,7 @@ impl Query {
                 const_fns: HashMap::new(),
                 query_paths: HashMap::new(),
                 internal_macros: HashMap::new(),
+                docs: HashMap::new(),
             })),
         }
     }
@@ -140,6 +142,23 @@ impl Query {
         Ok(())
     }
 
+    /// Associate doc strings with the item of the given id.
+    pub(crate) fn insert_docs(&self, id: Id, docs: Vec<String>) {
+        if !docs.is_empty() {
+            self.inner.borrow_mut().docs.insert(id, docs);
+        }
+    }
+
+    /// Get the doc strings associated with the item of the given id.
+    pub(crate) fn docs_for(&self, id: Id) -> Vec<String> {
+        self.inner
+            .borrow()
+            .docs
+            .get(&id)
+            .cloned()
+            .unwrap_or_default()
+    }
+
     /// Get the next build entry from the build queue associated with the query
     /// engine.
     pub(crate) fn next_build_entry(&self) -> Option<BuildEntry> {
@@ -714,6 +733,8 @@ struct QueryInner {
     query_paths: HashMap<Id, Rc<QueryPath>>,
     /// The result of internally resolved macros.
     internal_macros: HashMap<Id, Rc<BuiltInMacro>>,
+    /// Doc strings of items.
+    docs: HashMap<Id, Vec<String>>,
 }
 
 impl QueryInner {
@@ -1000,14 +1021,14 @@ impl QueryInner {
             imported = import_entry.imported.clone();
         }
 
-        let (kind, source) = match imported_meta {
-            Some(m) => (m.kind, m.source),
+        let (kind, source, docs) = match imported_meta {
+            Some(m) => (m.kind, m.source, m.docs),
             None => {
                 let kind = CompileMetaKind::Import {
                     imported: imported.clone(),
                 };
 
-                (kind, None)
+                (kind, None, Vec::new())
             }
         };
 
@@ -1018,6 +1039,7 @@ impl QueryInner {
                     item: p.item,
                     kind: kind.clone(),
                     source: source.clone(),
+                    docs: docs.clone(),
                 },
             )?
         }
@@ -1403,6 +1425,7 @@ impl QueryInner {
                 span: query_item.location.span,
                 path,
             }),
+            docs: self.docs.get(&query_item.id).cloned().unwrap_or_default(),
         })
     }
 
@@ -0,0 +1,242 @@
+//! Incremental evaluation of statements, as used by a REPL.
+
+use crate::{ast, Errors, Options, ParseError, Sources, Spanned as _, Warnings};
+use runestick::{Context, FromValue as _, Source, Value, Vm, VmError, VmExecution};
+use std::sync::Arc;
+use thiserror::Error;
+
+/// The name of the variable holding the value of the evaluated input.
+const VALUE: &str = "repl_value";
+
+/// An error raised while evaluating input in a [Repl].
+#[derive(Debug, Error)]
+pub enum ReplError {
+    /// The input could not be parsed.
+    #[error("parse error")]
+    ParseError(#[from] ParseError),
+    /// The input could not be compiled.
+    ///
+    /// The sources the errors refer to are available through
+    /// [Repl::sources].
+    #[error("load errors")]
+    Errors(Errors),
+    /// A virtual machine error was raised while running the input.
+    #[error("vm error")]
+    VmError(#[from] VmError),
+}
+
+/// Evaluates statements and expressions one at a time.
+///
+/// Variables declared using `let` stay in scope for any input which follows,
+/// and items like functions remain available once declared.
+///
+/// Each input is compiled into a function which receives the variables in
+/// scope as arguments and hands them back once it completes, so the values of
+/// variables persist between inputs.
+///
+/// # Examples
+///
+/// ```rust
+/// use rune::Repl;
+/// use runestick::FromValue as _;
+/// use std::sync::Arc;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let context = Arc::new(rune_modules::default_context()?);
+/// let mut repl = Repl::new(context);
+///
+/// repl.eval("let x = 1;")?;
+/// let output = i64::from_value(repl.eval("x + 1")?)?;
+/// assert_eq!(output, 2);
+/// # Ok(())
+/// # }
+/// ```
+pub struct Repl {
+    context: Arc<Context>,
+    options: Options,
+    /// Source of items declared so far.
+    items: String,
+    /// Names of the variables in scope.
+    names: Vec<String>,
+    /// Values of the variables in scope, in the same order as `names`.
+    values: Vec<Value>,
+    /// Sources of the most recently compiled input.
+    sources: Sources,
+}
+
+impl Repl {
+    /// Construct a new REPL using the given context.
+    pub fn new(context: Arc<Context>) -> Self {
+        Self {
+            context,
+            options: Options::default(),
+            items: String::new(),
+            names: Vec::new(),
+            values: Vec::new(),
+            sources: Sources::new(),
+        }
+    }
+
+    /// The sources of the most recently evaluated input.
+    ///
+    /// This can be used to emit diagnostics for [ReplError::Errors].
+    pub fn sources(&self) -> &Sources {
+        &self.sources
+    }
+
+    /// Iterate over the variables currently in scope.
+    pub fn variables(&self) -> impl Iterator<Item = (&str, &Value)> {
+        self.names
+            .iter()
+            .map(String::as_str)
+            .zip(self.values.iter())
+    }
+
+    /// Evaluate the given input, returning the value of its trailing
+    /// expression or unit if it has none.
+    ///
+    /// If any async instructions are encountered, this will error.
+    pub fn eval(&mut self, input: &str) -> Result<Value, ReplError> {
+        let (mut execution, pending) = self.prepare(input)?;
+        let output = execution.complete()?;
+        self.finish(pending, output)
+    }
+
+    /// Evaluate the given input with support for async functions.
+    pub async fn eval_async(&mut self, input: &str) -> Result<Value, ReplError> {
+        let (mut execution, pending) = self.prepare(input)?;
+        let output = execution.async_complete().await?;
+        self.finish(pending, output)
+    }
+
+    /// Compile the given input and set up its execution.
+    fn prepare(&mut self, input: &str) -> Result<(VmExecution, Pending), ReplError> {
+        let input = format!("{{{}\n}}", input);
+        let block = crate::parse_all::<ast::Block>(&input)?;
+
+        let mut items = String::new();
+        let mut body = String::new();
+        let mut names = self.names.clone();
+        let mut value = None;
+
+        let count = block.statements.len();
+
+        for (n, stmt) in block.statements.iter().enumerate() {
+            let source = &input[stmt.span().range()];
+
+            match stmt {
+                ast::Stmt::Item(..) => {
+                    items.push_str(source);
+                    items.push('\n');
+                }
+                ast::Stmt::Expr(expr, None) if n + 1 == count => {
+                    value = Some(&input[expr.span().range()]);
+                }
+                ast::Stmt::Local(local) => {
+                    let mut bindings = Vec::new();
+                    pat_bindings(&input, &local.pat, &mut bindings);
+
+                    for name in bindings {
+                        names.retain(|n| n != name);
+                        names.push(name.to_owned());
+                    }
+
+                    body.push_str(source);
+                    body.push('\n');
+                }
+                ast::Stmt::Expr(..) => {
+                    body.push_str(source);
+                    body.push('\n');
+                }
+            }
+        }
+
+        items.insert_str(0, &self.items);
+
+        let source = format!(
+            "{items}\npub fn main({args}) {{\n{body}let {value} = {{ {expr} }};\n({value}, [{names}])\n}}\n",
+            items = items,
+            args = self.names.join(", "),
+            body = body,
+            value = VALUE,
+            expr = value.unwrap_or("()"),
+            names = names.join(", "),
+        );
+
+        let mut sources = Sources::new();
+        sources.insert(Source::new("repl", source));
+
+        let mut errors = Errors::new();
+        let mut warnings = Warnings::disabled();
+
+        let unit = crate::load_sources(
+            &*self.context,
+            &self.options,
+            &mut sources,
+            &mut errors,
+            &mut warnings,
+        );
+
+        self.sources = sources;
+
+        let unit = match unit {
+            Ok(unit) => unit,
+            Err(..) => return Err(ReplError::Errors(errors)),
+        };
+
+        let vm = Vm::new(self.context.clone(), Arc::new(unit));
+        let execution = vm.execute(&["main"], self.values.clone())?;
+        Ok((execution, Pending { items, names }))
+    }
+
+    /// Commit the state produced by a completed execution.
+    fn finish(&mut self, pending: Pending, output: Value) -> Result<Value, ReplError> {
+        let (value, values) = <(Value, Vec<Value>)>::from_value(output)?;
+
+        self.items = pending.items;
+        self.names = pending.names;
+        self.values = values;
+        Ok(value)
+    }
+}
+
+/// State which is committed once an input has been successfully evaluated.
+struct Pending {
+    items: String,
+    names: Vec<String>,
+}
+
+/// Collect the names of all variables bound by the given pattern.
+fn pat_bindings<'a>(source: &'a str, pat: &ast::Pat, out: &mut Vec<&'a str>) {
+    match pat {
+        ast::Pat::PatPath(pat) => {
+            if let Some(ident) = pat.path.try_as_ident() {
+                let name = &source[ident.span().range()];
+
+                // NB: capitalized paths refer to types, like `None`.
+                if !name.starts_with(char::is_uppercase) {
+                    out.push(name);
+                }
+            }
+        }
+        ast::Pat::PatVec(pat) => {
+            for (pat, _) in pat.items.iter() {
+                pat_bindings(source, pat, out);
+            }
+        }
+        ast::Pat::PatTuple(pat) => {
+            for (pat, _) in pat.items.iter() {
+                pat_bindings(source, pat, out);
+            }
+        }
+        ast::Pat::PatObject(pat) => {
+            for (pat, _) in pat.items.iter() {
+                pat_bindings(source, pat, out);
+            }
+        }
+        ast::Pat::PatBinding(pat) => {
+            pat_bindings(source, &pat.pat, out);
+        }
+        ast::Pat::PatIgnore(..) | ast::Pat::PatLit(..) | ast::Pat::PatRest(..) => (),
+    }
+}
@@ -68,7 +68,25 @@ where
     A: runestick::Args,
     T: FromValue,
 {
-    let (unit, _) = compile_source(context, &source).map_err(RunError::Errors)?;
+    let (output, _) = run_async_with_warnings(context, function, args, source).await?;
+    Ok(output)
+}
+
+/// Call the specified function in the given script, returning the warnings
+/// produced while compiling it alongside the output.
+pub async fn run_async_with_warnings<N, A, T>(
+    context: &Arc<runestick::Context>,
+    function: N,
+    args: A,
+    source: &str,
+) -> Result<(T, Warnings), RunError>
+where
+    N: IntoIterator,
+    N::Item: IntoComponent,
+    A: runestick::Args,
+    T: FromValue,
+{
+    let (unit, warnings) = compile_source(context, &source).map_err(RunError::Errors)?;
 
     let vm = runestick::Vm::new(context.clone(), Arc::new(unit));
 
@@ -79,7 +97,8 @@ where
         .await
         .map_err(RunError::VmError)?;
 
-    T::from_value(output).map_err(RunError::VmError)
+    let output = T::from_value(output).map_err(RunError::VmError)?;
+    Ok((output, warnings))
 }
 
 /// Call the specified function in the given script.
@@ -98,6 +117,23 @@ where
     block_on(run_async(context, function, args, source))
 }
 
+/// Call the specified function in the given script, returning the warnings
+/// produced while compiling it alongside the output.
+pub fn run_with_warnings<N, A, T>(
+    context: &Arc<runestick::Context>,
+    function: N,
+    args: A,
+    source: &str,
+) -> Result<(T, Warnings), RunError>
+where
+    N: IntoIterator,
+    N::Item: IntoComponent,
+    A: runestick::Args,
+    T: runestick::FromValue,
+{
+    block_on(run_async_with_warnings(context, function, args, source))
+}
+
 /// Helper function to construct a context and unit from a Rune source for
 /// testing purposes.
 ///
@@ -188,6 +224,63 @@ macro_rules! rune {
     }};
 }
 
+/// Same as [rune!] macro, except it also asserts that compiling the program
+/// produces exactly the warnings matching the given patterns, in order.
+///
+/// An empty list of patterns asserts that the program compiles without any
+/// warnings.
+///
+/// # Examples
+///
+/// ```rust
+/// use rune::testing::*;
+///
+/// # fn main() {
+/// assert_eq! {
+///     rune::rune_warnings!(bool => [] => pub fn main() { true || false }),
+///     true,
+/// };
+///
+/// assert_eq! {
+///     rune::rune_warnings!(i64 => [NotUsed { .. }] => pub fn main() { 1; 2 }),
+///     2,
+/// };
+/// # }
+/// ```
+#[macro_export]
+macro_rules! rune_warnings {
+    ($ty:ty => [$($pat:pat),* $(,)?] => $($tt:tt)*) => {{
+        let context = ::rune_modules::default_context().expect("failed to build context");
+        let context = std::sync::Arc::new(context);
+
+        let (output, warnings) = $crate::testing::run_with_warnings::<_, (), $ty>(
+            &context,
+            &["main"],
+            (),
+            stringify!($($tt)*),
+        )
+        .expect("program to run successfully");
+
+        #[allow(unused_mut)]
+        let mut it = warnings.into_iter();
+
+        $(
+            match it.next().map(|w| w.kind) {
+                Some($pat) => (),
+                kind => {
+                    panic!("expected warning `{}` but was `{:?}`", stringify!($pat), kind);
+                }
+            }
+        )*
+
+        if let Some(warning) = it.next() {
+            panic!("unexpected warning `{:?}`", warning.kind);
+        }
+
+        output
+    }};
+}
+
 /// Function used during parse testing to take the source, parse it as the given
 /// type, tokenize it using [ToTokens][crate::macros::ToTokens], and parse the
 /// token stream.
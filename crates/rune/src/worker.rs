@@ -75,6 +75,12 @@ impl<'a> Worker<'a> {
         source_loader: &'a mut dyn SourceLoader,
         storage: Storage,
     ) -> Self {
+        // NB: constants provided by the context are seeded so that they can be
+        // used in constant expressions.
+        for (item, const_value) in context.iter_constants() {
+            consts.insert(item.clone(), const_value.clone());
+        }
+
         Self {
             context,
             sources,
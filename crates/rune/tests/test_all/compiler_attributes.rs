@@ -10,3 +10,83 @@ fn test_bad_attributes() {
         }
     };
 }
+
+#[test]
+fn test_doc_comments() {
+    let context = rune_modules::default_context().unwrap();
+
+    let (unit, _) = rune::testing::compile_source(
+        &context,
+        r#"
+        /// Add two numbers.
+        ///
+        /// Returns the sum.
+        pub fn add(a, b) { a + b }
+
+        //// Not a doc comment.
+        pub fn main() { add(1, 2) }
+        "#,
+    )
+    .expect("source should compile");
+
+    let debug = unit.debug_info().expect("debug info");
+
+    let add = &debug.functions[&runestick::Hash::type_hash(&["add"])];
+    assert_eq!(add.docs, vec![" Add two numbers.", "", " Returns the sum."]);
+
+    let main = &debug.functions[&runestick::Hash::type_hash(&["main"])];
+    assert!(main.docs.is_empty());
+}
+
+#[test]
+fn test_doc_comments_on_items() {
+    let context = rune_modules::default_context().unwrap();
+
+    let (unit, _) = rune::testing::compile_source(
+        &context,
+        r#"
+        /// A point.
+        struct Point(x, y);
+
+        impl Point {
+            /// The sum of the coordinates.
+            fn sum(self) { self.0 + self.1 }
+        }
+
+        /// The origin.
+        const ORIGIN = 0;
+
+        /// An option.
+        enum Opt {
+            /// Some value.
+            Some(value),
+            /// No value.
+            None,
+        }
+
+        pub fn main() { Point(ORIGIN, 2).sum() + Opt::Some(1).0 }
+        "#,
+    )
+    .expect("source should compile");
+
+    let debug = unit.debug_info().expect("debug info");
+
+    let docs = |path: &[&str]| {
+        let path = runestick::Item::of(path);
+
+        debug
+            .functions
+            .values()
+            .find(|f| f.path == path)
+            .map(|f| f.docs.clone())
+            .expect("missing function")
+    };
+
+    assert_eq!(docs(&["Point"]), vec![" A point."]);
+    assert_eq!(
+        docs(&["Point", "sum"]),
+        vec![" The sum of the coordinates."]
+    );
+    assert_eq!(docs(&["Opt", "Some"]), vec![" Some value."]);
+    assert_eq!(docs(&["Opt", "None"]), vec![" No value."]);
+}
@@ -11,3 +11,14 @@ fn test_assign_exprs() {
         }
     };
 }
+
+#[test]
+fn test_compound_assign_missing_local() {
+    assert_compile_error! {
+        r#"pub fn main() { missing += 1; }"#,
+        span, MissingLocal { name } => {
+            assert_eq!(span, Span::new(16, 28));
+            assert_eq!(name, "missing");
+        }
+    };
+}
@@ -15,4 +15,13 @@ fn test_binary_exprs() {
     assert_parse!(r#"pub fn main() { 0 < 10 && 10 > 0 }"#);
     assert_parse!(r#"pub fn main() { 0 < 10 && 10 > 0 || true }"#);
     assert_parse!(r#"pub fn main() { false || return }"#);
+
+    assert_parse_error! {
+        r#"pub fn main() { 2 ** 3 ** 2 }"#,
+        span, PrecedenceGroupRequired => {
+            assert_eq!(span, Span::new(16, 22));
+        }
+    };
+
+    assert_parse!(r#"pub fn main() { 2 ** (3 ** 2) }"#);
 }
@@ -12,7 +12,7 @@ fn test_fn_const_async() {
     assert_compile_error! {
         r#"pub const fn main() { yield true }"#,
         span, FnConstNotGenerator => {
-            assert_eq!(span, Span::new(0, 34));
+            assert_eq!(span, Span::new(22, 32));
         }
     };
 }
@@ -20,6 +20,61 @@ fn break_outside_of_loop() {
     };
 }
 
+#[test]
+fn continue_outside_of_loop() {
+    assert_compile_error! {
+        r#"pub fn main() { continue; }"#,
+        span, ContinueOutsideOfLoop => {
+            assert_eq!(span, Span::new(16, 24));
+        }
+    };
+}
+
+#[test]
+fn await_outside_of_async() {
+    assert_compile_error! {
+        r#"pub fn main(f) { f.await }"#,
+        span, AwaitOutsideFunction => {
+            assert_eq!(span, Span::new(17, 24));
+        }
+    };
+
+    assert_compile_error! {
+        r#"pub async fn main(f) { let c = || f.await; c() }"#,
+        span, AwaitOutsideFunction => {
+            assert_eq!(span, Span::new(34, 41));
+        }
+    };
+}
+
+#[test]
+fn yield_outside_of_generator() {
+    assert_compile_error! {
+        r#"const fn values() { yield 1; } pub fn main() { values() }"#,
+        span, FnConstNotGenerator => {
+            assert_eq!(span, Span::new(20, 27));
+        }
+    };
+
+    assert_compile_error! {
+        r#"const VALUE = { yield 1 }; pub fn main() { VALUE }"#,
+        span, YieldOutsideFunction => {
+            assert_eq!(span, Span::new(16, 23));
+        }
+    };
+}
+
+#[test]
+fn continue_missing_label() {
+    assert_compile_error! {
+        r#"pub fn main() { loop { continue 'outer; } }"#,
+        span, MissingLoopLabel { label } => {
+            assert_eq!(&*label, "outer");
+            assert_eq!(span, Span::new(32, 38));
+        }
+    };
+}
+
 #[test]
 fn test_pointers() {
     assert_compile_error! {
@@ -84,3 +139,132 @@ fn test_bad_struct_declaration() {
         }
     };
 }
+
+#[test]
+fn test_bad_struct_variant_literal() {
+    assert_compile_error! {
+        r#"enum Foo { Bar { a, b, c } } pub fn main() { Foo::Bar { b: 1 } }"#,
+        span, LitObjectMissingField { field, .. } => {
+            assert_eq!(span, Span::new(45, 62));
+            assert_eq!(field.as_ref(), "a");
+        }
+    };
+
+    assert_compile_error! {
+        r#"enum Foo { Bar { a } } pub fn main() { Foo::Bar { a: 1, d: 2 } }"#,
+        span, LitObjectNotField { field, .. } => {
+            assert_eq!(span, Span::new(56, 57));
+            assert_eq!(field.as_ref(), "d");
+        }
+    };
+}
+
+#[test]
+fn test_bad_struct_literal() {
+    assert_compile_error! {
+        r#"struct Point { x, y } pub fn main() { Point { x: 1 } }"#,
+        span, LitObjectMissingField { field, .. } => {
+            assert_eq!(span, Span::new(38, 52));
+            assert_eq!(field.as_ref(), "y");
+        }
+    };
+
+    assert_compile_error! {
+        r#"struct Point { x } pub fn main() { Point { x: 1, z: 2 } }"#,
+        span, LitObjectNotField { field, .. } => {
+            assert_eq!(span, Span::new(49, 50));
+            assert_eq!(field.as_ref(), "z");
+        }
+    };
+}
+
+#[test]
+fn test_unit_call() {
+    assert_compile_error! {
+        r#"struct Empty; pub fn main() { Empty() }"#,
+        span, UnsupportedUnitCall { .. } => {
+            assert_eq!(span, Span::new(30, 37));
+        }
+    };
+
+    assert_compile_error! {
+        r#"enum Status { Active } pub fn main() { Status::Active(1) }"#,
+        span, UnsupportedUnitCall { .. } => {
+            assert_eq!(span, Span::new(39, 56));
+        }
+    };
+}
+
+#[test]
+fn test_disassemble_unit() {
+    let context = rune_modules::default_context().unwrap();
+    let (unit, _) = compile_source(
+        &context,
+        r#"pub fn main(n) { while n < 10 { n += 1; } "done" }"#,
+    )
+    .unwrap();
+
+    let listing = unit.disassemble();
+
+    assert!(listing.contains("fn main(n)"));
+    assert!(listing
+        .lines()
+        .any(|l| l.contains("jump") && l.contains("// -> ")));
+    assert!(listing
+        .lines()
+        .any(|l| l.trim_start().ends_with("= return")));
+    assert!(listing.contains("# strings\n  0000 = \"done\""));
+}
+
+#[test]
+fn test_disassemble_unit_with_source() {
+    let source = "pub fn main(n) {\n    let a = n + 1;\n    a * 2\n}\n";
+
+    let context = rune_modules::default_context().unwrap();
+    let (unit, _) = compile_source(&context, source).unwrap();
+
+    let source = runestick::Source::new("main", source);
+    let listing = unit.disassemble_with_source(|_| Some(&source));
+
+    let expected = r#"fn main(n) (0xa76ee18c7fed2b52):
+   2 |     let a = n + 1;
+  0000 = copy 0 // var `n`
+  0001 = push 1
+  0002 = op +
+   3 |     a * 2
+  0003 = copy 1 // var `a`
+  0004 = push 2
+  0005 = op *
+   1 | pub fn main(n) {
+  0006 = clean 1
+  0007 = clean 1
+  0008 = return
+"#;
+
+    assert_eq!(listing, expected);
+}
+
+#[test]
+fn test_function_instructions() {
+    let source = "fn helper() { 1 }\npub fn main() {\n    helper() + 2\n}\n";
+
+    let context = rune_modules::default_context().unwrap();
+    let (unit, _) = compile_source(&context, source).unwrap();
+
+    for name in &["main", "helper"] {
+        let hash = runestick::Hash::type_hash(&[*name]);
+        let instructions = unit.function_instructions(hash).unwrap();
+        assert!(matches!(
+            instructions.last(),
+            Some(runestick::Inst::Return) | Some(runestick::Inst::ReturnUnit)
+        ));
+    }
+
+    let offset = match unit.lookup(runestick::Hash::type_hash(&["main"])) {
+        Some(runestick::UnitFn::Offset { offset, .. }) => offset,
+        _ => panic!("expected function offset"),
+    };
+
+    let (_, span) = unit.instruction_span(offset).unwrap();
+    assert_eq!(&source[span.range()], "helper()");
+}
@@ -39,3 +39,69 @@ fn test_number_literals() {
         }
     };
 }
+
+#[test]
+fn test_negative_number_literals() {
+    use runestick::{Inst, InstValue};
+
+    macro_rules! assert_single_push {
+        ($source:expr, $pat:pat $(if $cond:expr)?) => {{
+            let (unit, _) = assert_parse!($source);
+            let instructions = unit.iter_instructions().collect::<Vec<_>>();
+
+            assert!(
+                matches!(
+                    instructions.as_slice(),
+                    [Inst::Push { value: $pat }, Inst::Return] $(if $cond)?
+                ),
+                "unexpected instructions {:?}",
+                instructions
+            );
+        }};
+    }
+
+    assert_single_push!(r#"pub fn main() { -5 }"#, InstValue::Integer(-5));
+    assert_single_push!(
+        r#"pub fn main() { -9223372036854775808 }"#,
+        InstValue::Integer(std::i64::MIN)
+    );
+    assert_single_push!(r#"pub fn main() { -5.5 }"#, InstValue::Float(n) if *n == -5.5);
+}
+
+#[test]
+fn test_identical_literals_share_slot() {
+    use runestick::Inst;
+
+    let strings = std::iter::repeat(r#""ok""#).take(100).collect::<Vec<_>>();
+    let bytes = std::iter::repeat(r#"b"ok""#).take(100).collect::<Vec<_>>();
+
+    let source = format!(
+        "pub fn main() {{ ([{}], [{}]) }}",
+        strings.join(", "),
+        bytes.join(", ")
+    );
+
+    let (unit, _) = assert_parse!(&source);
+
+    assert_eq!(unit.iter_static_strings().count(), 1);
+    assert_eq!(unit.iter_static_bytes().collect::<Vec<_>>(), vec![b"ok"]);
+
+    let mut string_slots = 0;
+    let mut bytes_slots = 0;
+
+    for inst in unit.iter_instructions() {
+        match inst {
+            Inst::String { slot } => {
+                assert_eq!(slot, 0);
+                string_slots += 1;
+            }
+            Inst::Bytes { slot } => {
+                assert_eq!(slot, 0);
+                bytes_slots += 1;
+            }
+            _ => (),
+        }
+    }
+
+    assert_eq!((string_slots, bytes_slots), (100, 100));
+}
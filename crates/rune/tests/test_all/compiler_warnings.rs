@@ -29,3 +29,112 @@ fn test_remove_variant_parens() {
         }
     };
 }
+
+#[test]
+fn test_assign_to_temporary() {
+    assert_warnings! {
+        r#"pub fn main() { (1, 2).0 = 5; }"#,
+        AssignToTemporary { span, .. } => {
+            assert_eq!(span, Span::new(16, 22));
+        }
+    };
+
+    assert_warnings! {
+        r#"pub fn main() { #{a: (1, 2)}.a.0 += 5; }"#,
+        AssignToTemporary { span, .. } => {
+            assert_eq!(span, Span::new(16, 28));
+        }
+    };
+
+    assert_warnings! {
+        r#"pub fn main() { [1, 2][0] = 5; }"#,
+        AssignToTemporary { span, .. } => {
+            assert_eq!(span, Span::new(16, 22));
+        }
+    };
+
+    assert_eq! {
+        rune::rune_warnings!((i64, i64) => [] =>
+            pub fn main() { let pair = (1, 2); pair.0 = 5; pair }
+        ),
+        (5, 2),
+    };
+}
+
+#[test]
+fn test_unused_variable() {
+    assert_warnings! {
+        r#"pub fn main() { let unused = 1; }"#,
+        UnusedVariable { span, .. } => {
+            assert_eq!(span, Span::new(20, 26));
+        }
+    };
+
+    assert_warnings! {
+        r#"pub fn main() { for i in 0..10 {} }"#,
+        UnusedVariable { span, .. } => {
+            assert_eq!(span, Span::new(20, 21));
+        }
+    };
+
+    assert_eq! {
+        rune::rune_warnings!(i64 => [] =>
+            pub fn main() { let _unused = 1; let used = 2; let moved = || used; moved() }
+        ),
+        2,
+    };
+}
+
+#[test]
+fn test_rune_warnings() {
+    assert_eq! {
+        rune::rune_warnings!(i64 => [] => pub fn main() { let a = 1; a + 2 }),
+        3,
+    };
+
+    assert_eq! {
+        rune::rune_warnings!(i64 => [NotUsed { .. }] => pub fn main() { 1; 2 }),
+        2,
+    };
+}
+
+#[test]
+#[should_panic(expected = "unexpected warning")]
+fn test_rune_warnings_unexpected() {
+    rune::rune_warnings!(i64 => [] => pub fn main() { 1; 2 });
+}
+
+#[test]
+fn test_unreachable() {
+    assert_warnings! {
+        r#"pub fn main() { return 1; let a = 2; a }"#,
+        Unreachable { span, cause } => {
+            assert_eq!(span, Span::new(26, 36));
+            assert_eq!(cause, Span::new(16, 24));
+        }
+    };
+
+    assert_warnings! {
+        r#"pub fn main() { let n = 0; loop { break; n += 1; n += 2; } loop {} n }"#,
+        Unreachable { span, cause } => {
+            assert_eq!(span, Span::new(41, 47));
+            assert_eq!(cause, Span::new(34, 39));
+        },
+        Unreachable { span, cause } => {
+            assert_eq!(span, Span::new(67, 68));
+            assert_eq!(cause, Span::new(59, 66));
+        }
+    };
+
+    assert_eq! {
+        rune::rune_warnings!(i64 => [] =>
+            pub fn main() {
+                let n = 0;
+                loop { if n == 2 { break; } n += 1; }
+                if n > 1 { return n; }
+                0
+            }
+        ),
+        2,
+    };
+}
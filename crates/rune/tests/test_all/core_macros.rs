@@ -31,6 +31,7 @@ fn test_format() {
     test_case!("Hello, {name}", name = "John Doe");
     test_case!("Hello, {1} {0}", "John", "Doe");
     test_case!("Hello, {} {0} {}", "John", "Doe");
+    test_case!("{:x} {:b}", 255, 5);
 
     let out: String =
         rune!(String => pub fn main() { format!("Hello, {}" + " {0} {}", "John", "Doe") });
@@ -72,6 +73,15 @@ fn test_strings() {
     test_case!("{:\n>99?}", "test\tstring");
 }
 
+#[test]
+fn test_string_precision() {
+    test_case!("{:.3}", "hello");
+    test_case!("{:.3}", "hé😊llo");
+    test_case!("{:.10}", "hé😊llo");
+    test_case!("{:/>6.3}", "hé😊llo");
+    test_case!("{:.*}", 2, "hé😊llo");
+}
+
 #[test]
 fn test_float_formatting() {
     test_case!("{:.10}", 3.1415);
@@ -103,4 +113,8 @@ fn test_number_formatting() {
     test_case!("{:/<13b}", 42);
     test_case!("{:/^13b}", 42);
     test_case!("{:/>13b}", 42);
+
+    test_case!("{:/<13o}", 42);
+    test_case!("{:/^13o}", 42);
+    test_case!("{:/>13o}", 42);
 }
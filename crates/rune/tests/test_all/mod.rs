@@ -10,26 +10,39 @@ mod compiler_visibility;
 mod compiler_warnings;
 mod core_macros;
 mod moved;
+mod repl;
 mod vm_arithmetic;
 mod vm_assign_exprs;
 mod vm_async_block;
 mod vm_blocks;
+mod vm_char;
 mod vm_closures;
 mod vm_const_exprs;
 mod vm_early_termination;
 mod vm_function;
 mod vm_general;
 mod vm_generators;
+mod vm_in;
 mod vm_is;
 mod vm_lazy_and_or;
 mod vm_literals;
 mod vm_match;
 mod vm_not_used;
+mod vm_objects;
 mod vm_option;
 mod vm_pat;
+mod vm_range;
 mod vm_result;
+mod vm_select;
 mod vm_streams;
+mod vm_test_chars_and_bytes;
+mod vm_test_external_constants;
+mod vm_test_external_downcast;
+mod vm_test_external_drop;
+mod vm_test_external_executor;
 mod vm_test_external_fn_ptr;
+mod vm_test_external_index;
+mod vm_test_from_iter;
 mod vm_test_from_value_derive;
 mod vm_test_imports;
 mod vm_test_instance_fns;
@@ -40,4 +53,5 @@ mod vm_try;
 mod vm_tuples;
 mod vm_typed_tuple;
 mod vm_types;
+mod vm_vec;
 mod wildcard_imports;
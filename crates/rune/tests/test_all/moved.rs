@@ -43,3 +43,14 @@ fn test_async_moved() {
         }
     )
 }
+
+#[test]
+fn test_closure_moved_expr() {
+    assert_compile_error!(
+        r#"pub fn main() { let x = 1; let a = move || x; x }"#,
+        span, VariableMoved { moved_at } => {
+            assert_eq!(span, Span::new(46, 47));
+            assert_eq!(moved_at, Span::new(35, 44));
+        }
+    )
+}
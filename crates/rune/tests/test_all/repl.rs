@@ -0,0 +1,43 @@
+use rune::Repl;
+use runestick::FromValue as _;
+use std::sync::Arc;
+
+fn repl() -> Repl {
+    Repl::new(Arc::new(rune_modules::default_context().unwrap()))
+}
+
+#[test]
+fn test_repl_variables() {
+    let mut repl = repl();
+
+    assert!(repl.eval("let x = 1;").unwrap().into_unit().is_ok());
+    assert_eq!(i64::from_value(repl.eval("x + 1").unwrap()).unwrap(), 2);
+
+    // NB: shadowing replaces the old variable.
+    repl.eval("let x = x + 10; let (a, b) = (x, 2);").unwrap();
+    assert_eq!(
+        <(i64, i64, i64)>::from_value(repl.eval("(x, a, b)").unwrap()).unwrap(),
+        (11, 11, 2)
+    );
+
+    let names = repl.variables().map(|(n, _)| n).collect::<Vec<_>>();
+    assert_eq!(names, vec!["x", "a", "b"]);
+}
+
+#[test]
+fn test_repl_items() {
+    let mut repl = repl();
+
+    repl.eval("fn add(a, b) { a + b }").unwrap();
+    assert_eq!(i64::from_value(repl.eval("add(1, 2)").unwrap()).unwrap(), 3);
+}
+
+#[test]
+fn test_repl_errors_keep_state() {
+    let mut repl = repl();
+
+    repl.eval("let x = 1;").unwrap();
+    assert!(repl.eval("let y = x + missing;").is_err());
+    assert!(repl.eval("let z = (x, 1).3;").is_err());
+    assert_eq!(i64::from_value(repl.eval("x").unwrap()).unwrap(), 1);
+}
@@ -145,6 +145,20 @@ fn test_div() {
 fn test_rem() {
     op_tests!(10 % 3 = 1);
     error_test!(10 % 0 = DivideByZero);
+
+    assert_eq!(rune!(i64 => pub fn main() { 17 % 5 }), 2);
+    assert_eq!(rune!(f64 => pub fn main() { 7.5 % 2.0 }), 1.5);
+}
+
+#[test]
+fn test_pow() {
+    assert_eq!(rune!(i64 => pub fn main() { 2 ** 10 }), 1024);
+    assert_eq!(rune!(i64 => pub fn main() { 0 ** 0 }), 1);
+    assert_eq!(rune!(i64 => pub fn main() { 2 * 3 ** 2 }), 18);
+    assert_eq!(rune!(f64 => pub fn main() { 2.0 ** 0.5 }), 2f64.powf(0.5));
+
+    assert_vm_error!(r#"pub fn main() { let a = 2; a ** 64 }"#, Overflow => {});
+    assert_vm_error!(r#"pub fn main() { let a = 2; a ** -1 }"#, Overflow => {});
 }
 
 #[test]
@@ -155,6 +169,14 @@ fn test_bit_ops() {
     op_tests!(0b1100 << 2 = 0b1100 << 2);
     op_tests!(0b1100 >> 2 = 0b1100 >> 2);
     error_test!(0b1 << 64 = Overflow);
+    error_test!(0b1 << -1 = Overflow);
+    error_test!(0b1 >> 64 = Overflow);
+    error_test!(0b1 >> -1 = Overflow);
+
+    assert_eq!(
+        rune!(bool => pub fn main() { 0b1010 & 0b0110 == 0b0010 }),
+        true
+    );
 }
 
 #[test]
@@ -166,3 +188,73 @@ fn test_bitwise_not() {
         !0b10100,
     };
 }
+
+#[test]
+fn test_neg() {
+    assert_eq! {
+        rune!((i64, bool) => pub fn main() { let a = 5; let b = -a; (b, b is int) }),
+        (-5, true),
+    };
+
+    assert_eq! {
+        rune!((f64, bool) => pub fn main() { let a = 5.0; let b = -a; (b, b is float) }),
+        (-5.0, true),
+    };
+
+    assert_eq! {
+        rune!((i64, f64, bool) => pub fn main() { let b = -5.0; (-5, b, b is float) }),
+        (-5, -5.0, true),
+    };
+
+    assert_vm_error!(
+        r#"pub fn main() { let min = -9223372036854775808; -min }"#,
+        Overflow => {}
+    );
+
+    assert_eq! {
+        rune! { (i64, Option<i64>, i64) =>
+            pub fn main() {
+                let min = -9223372036854775808;
+                (min.wrapping_neg(), min.checked_neg(), min.saturating_neg())
+            }
+        },
+        (i64::MIN, None, i64::MAX),
+    };
+}
+
+#[test]
+fn test_overflow_builtins() {
+    assert_eq! {
+        rune! { (i64, i64, i64, i64, Option<i64>, Option<i64>) =>
+            pub fn main() {
+                let max = 9223372036854775807;
+                let min = -9223372036854775807 - 1;
+
+                (
+                    max.wrapping_add(1),
+                    min.wrapping_sub(1),
+                    max.wrapping_mul(2),
+                    max.saturating_add(1),
+                    max.checked_add(1),
+                    max.checked_add(-1),
+                )
+            }
+        },
+        (
+            i64::MIN,
+            i64::MAX,
+            -2,
+            i64::MAX,
+            None,
+            Some(i64::MAX - 1),
+        ),
+    };
+}
+
+#[test]
+fn test_rem_left_associative() {
+    assert_eq! {
+        rune!((i64, i64, i64) => pub fn main() { (17 % 10 % 3, 100 / 10 % 3, 7 % 4 * 3) }),
+        (1, 1, 9),
+    };
+}
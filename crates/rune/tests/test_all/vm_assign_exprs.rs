@@ -64,6 +64,22 @@ fn test_assign_tuple() {
     };
 }
 
+#[test]
+fn test_assign_through_call() {
+    assert_eq! {
+        (5, 2),
+        rune! { (i64, i64) =>
+            fn get_pair(pair) { pair }
+
+            pub fn main() {
+                let pair = (1, 2);
+                get_pair(pair).0 = 5;
+                pair
+            }
+        }
+    };
+}
+
 #[test]
 fn test_assign_assign_exprs() {
     assert_eq! {
@@ -82,3 +98,91 @@ fn test_assign_assign_exprs() {
         }
     };
 }
+
+#[test]
+fn test_assign_index_binop() {
+    assert_eq! {
+        (42, 7, 12),
+        rune! { (i64, i64, i64) =>
+            pub fn main() {
+                let v = [1, 2];
+                v[1] += 40;
+
+                let o = #{ x: 14 };
+                o["x"] /= 2;
+
+                let t = (0, 3);
+                t[1] <<= 2;
+
+                (v[1], o["x"], t[1])
+            }
+        }
+    };
+}
+
+#[test]
+fn test_assign_index_binop_evaluates_once() {
+    assert_eq! {
+        (2, 1, 1),
+        rune! { (i64, i64, i64) =>
+            pub fn main() {
+                let v = [1, 2];
+                let calls = #{ target: 0, index: 0 };
+
+                let target = || { calls.target += 1; v };
+                let index = || { calls.index += 1; 0 };
+
+                target()[index()] += 1;
+                (v[0], calls.target, calls.index)
+            }
+        }
+    };
+}
+
+#[test]
+fn test_assign_index_binop_instructions() {
+    let context = rune_modules::default_context().unwrap();
+
+    let count = |source: &str| {
+        let (unit, _) = rune::testing::compile_source(&context, source).unwrap();
+        let mut count = 0;
+        let mut swaps = 0;
+
+        for inst in unit.iter_instructions() {
+            count += 1;
+
+            if let runestick::Inst::Swap { .. } = inst {
+                swaps += 1;
+            }
+        }
+
+        (count, swaps)
+    };
+
+    let (compound, swaps) = count("pub fn main(v, i) { v[i] += 1; }");
+    let (expanded, _) = count("pub fn main(v, i) { let t = v; let j = i; t[j] = t[j] + 1; }");
+
+    // NB: like the expansion, the target and index are only evaluated once.
+    // But they are re-ordered in place for the index set instead of copied.
+    assert_eq!(swaps, 2);
+    assert!(compound <= expanded, "{} > {}", compound, expanded);
+}
+
+#[test]
+fn test_assign_index_string() {
+    use rune::testing::*;
+
+    assert_vm_error!(
+        r#"pub fn main() { let s = "hi"; s[0] = 'x'; }"#,
+        NotIndexAssignable { type_info } => {
+            assert_eq!(type_info.to_string(), "String");
+        }
+    );
+
+    assert_vm_error!(
+        r#"pub fn main() { let s = String::from_str("hi"); s[0] = 'x'; }"#,
+        NotIndexAssignable { type_info } => {
+            assert_eq!(type_info.to_string(), "String");
+        }
+    );
+}
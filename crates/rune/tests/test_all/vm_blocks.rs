@@ -11,3 +11,18 @@ fn test_anonymous_type_precedence() {
         }
     };
 }
+
+#[test]
+fn test_let_after_statement() {
+    assert_eq! {
+        2,
+        rune! { i64 =>
+            pub fn main() {
+                let n = 1;
+                n = n + 1;
+                let value = n;
+                value
+            }
+        }
+    };
+}
@@ -0,0 +1,37 @@
+#[test]
+fn test_char_predicates() {
+    assert_eq! {
+        rune!((bool, bool, bool, bool) => pub fn main() {
+            ('a'.is_alphabetic(), '1'.is_alphabetic(), '1'.is_numeric(), ' '.is_whitespace())
+        }),
+        (true, false, true, true)
+    };
+
+    assert_eq! {
+        rune!((bool, bool, bool) => pub fn main() {
+            ('a'.is_lowercase(), 'a'.is_uppercase(), '_'.is_alphanumeric())
+        }),
+        (true, false, false)
+    };
+}
+
+#[test]
+fn test_char_case_conversions() {
+    assert_eq! {
+        rune!((char, char, char) => pub fn main() {
+            ('A'.to_lowercase(), 'a'.to_uppercase(), '1'.to_uppercase())
+        }),
+        ('a', 'A', '1')
+    };
+
+    // NB: only the first character of multi-character mappings is used.
+    assert_eq!(rune!(char => pub fn main() { 'ß'.to_uppercase() }), 'S');
+}
+
+#[test]
+fn test_char_to_string() {
+    assert_eq!(
+        rune!(String => pub fn main() { 'a'.to_string() + "b" }),
+        "ab"
+    );
+}
@@ -1,3 +1,6 @@
+use rune::testing::*;
+use runestick::Inst;
+
 macro_rules! test_op {
     ($ty:ty => $lhs:literal $op:tt $rhs:literal = $result:literal) => {{
         let program = format!(
@@ -264,3 +267,60 @@ fn test_const_block() {
 
     assert_eq!(result, "Hello World");
 }
+
+#[test]
+fn test_const_if() {
+    assert_eq!(1, rune!(i64 => pub fn main() { if true { 1 } else { 2 } }));
+    assert_eq!(2, rune!(i64 => pub fn main() { if 1 > 2 { 1 } else { 2 } }));
+
+    let (unit, _) = assert_parse!(r#"pub fn main() { if true { 1 } else { 2 } }"#);
+
+    for inst in unit.iter_instructions() {
+        assert!(
+            !matches!(inst, Inst::Jump { .. } | Inst::JumpIf { .. }),
+            "unexpected jump instruction `{}`",
+            inst
+        );
+    }
+}
+
+#[test]
+fn test_const_fold_binary() {
+    assert_eq!(7, rune!(i64 => pub fn main() { 1 + 2 * 3 }));
+    assert_eq!(1, rune!(i64 => pub fn main() { 10 % 3 }));
+    assert_eq!(6, rune!(i64 => pub fn main() { let a = 1; a + 2 + 3 }));
+
+    let (unit, _) = assert_parse!(r#"pub fn main() { 1 + 2 * 3 }"#);
+    let instructions = unit.iter_instructions().collect::<Vec<_>>();
+
+    assert!(
+        matches!(
+            instructions.as_slice(),
+            [
+                Inst::Push {
+                    value: runestick::InstValue::Integer(7)
+                },
+                Inst::Return
+            ]
+        ),
+        "unexpected instructions {:?}",
+        instructions
+    );
+}
+
+#[test]
+fn test_const_fold_divide_by_zero() {
+    assert_compile_error! {
+        r#"pub fn main() { 1 / 0 }"#,
+        span, IrError { .. } => {
+            assert_eq!(span, Span::new(16, 21));
+        }
+    };
+
+    assert_compile_error! {
+        r#"pub fn main() { 1 % (2 - 2) }"#,
+        span, IrError { .. } => {
+            assert_eq!(span, Span::new(16, 27));
+        }
+    };
+}
@@ -137,6 +137,27 @@ fn test_vectors() {
         rune!(() => pub fn main() { let v = [1, 2, 3, 4, 5]; }),
         (),
     };
+
+    assert_eq! {
+        rune!((Vec<i64>, ()) => pub fn main() { ([], ()) }),
+        (vec![], ()),
+    };
+}
+
+#[test]
+fn test_nan_equality() {
+    assert_eq! {
+        rune! { (bool, bool, bool, bool, bool) =>
+            pub fn main() {
+                let nan = 0.0 / 0.0;
+                let v = [nan];
+                let o = #{a: [nan]};
+
+                ([0.0 / 0.0] == [0.0 / 0.0], [1.0] == [1.0], v == v, o == o, v != v)
+            }
+        },
+        (false, true, false, false, true),
+    };
 }
 
 #[test]
@@ -156,24 +177,62 @@ fn test_while() {
         10,
     };
 
-    assert_eq! {
-        rune! { i64 =>
-            pub fn main() {
-                let a = 0;
+    assert_compile_error! {
+        r#"pub fn main() { let c = true; while c { break 5 } }"#,
+        span, BreakValueOutsideOfLoop { loop_span } => {
+            assert_eq!(span, Span::new(40, 47));
+            assert_eq!(loop_span, Span::new(30, 49));
+        }
+    };
+}
 
-                let a = while a >= 0 {
-                    if a >= 10 {
-                        break a;
-                    }
+#[test]
+fn test_while_locals_stack_depth() {
+    use std::sync::Arc;
+
+    /// Run `main` one instruction at a time and return the maximum stack
+    /// depth observed.
+    fn max_stack_depth(source: &str) -> usize {
+        let context = Arc::new(rune_modules::default_context().unwrap());
+        let (unit, _) = rune::testing::compile_source(&context, source).unwrap();
+        let vm = runestick::Vm::new(context, Arc::new(unit));
+        let mut execution = vm.execute(&["main"], ()).unwrap();
+        let mut max = 0;
+
+        while execution.step().unwrap().is_none() {
+            max = usize::max(max, execution.vm().unwrap().stack().len());
+        }
 
-                    a = a + 1;
-                };
+        max
+    }
 
-                a
-            }
-        },
-        10,
+    let source = |n: usize| {
+        format!(
+            r#"
+            pub fn main() {{
+                let n = 0;
+
+                while n < {} {{
+                    let a = n;
+                    let b = a * 2;
+                    n += 1;
+
+                    if b % 4 == 0 {{
+                        let c = b;
+                        continue;
+                    }}
+
+                    {{ let d = b; }}
+                }}
+
+                n
+            }}
+            "#,
+            n
+        )
     };
+
+    assert_eq!(max_stack_depth(&source(10)), max_stack_depth(&source(1000)));
 }
 
 #[test]
@@ -216,6 +275,16 @@ fn test_loop() {
         },
         10,
     };
+
+    assert_eq! {
+        rune!(i64 => pub fn main() { let x = loop { break 5; }; x }),
+        5,
+    };
+
+    assert_eq! {
+        rune!(bool => pub fn main() { let x = loop { break; }; x is unit }),
+        true,
+    };
 }
 
 #[test]
@@ -238,8 +307,16 @@ fn test_for() {
         10,
     };
 
+    assert_compile_error! {
+        r#"pub fn main() { for v in [1] { break v; } }"#,
+        span, BreakValueOutsideOfLoop { loop_span } => {
+            assert_eq!(span, Span::new(31, 38));
+            assert_eq!(loop_span, Span::new(16, 41));
+        }
+    };
+
     assert_eq! {
-        rune! { i64 =>
+        rune! { bool =>
             use std::iter::range;
 
             pub fn main() {
@@ -248,38 +325,174 @@ fn test_for() {
 
                 let a = for v in it {
                     if a >= 10 {
-                        break a;
+                        break;
                     }
 
                     a = a + 1;
                 };
 
-                a
+                a is unit
             }
         },
-        10,
+        true,
     };
 
     assert_eq! {
-        rune! { bool =>
-            use std::iter::range;
+        rune! { i64 =>
+            pub fn main() {
+                let sum = 0;
+
+                for n in [1, 2, 3] {
+                    sum = sum + n;
+                }
 
+                sum
+            }
+        },
+        6,
+    };
+
+    assert_eq! {
+        rune! { i64 =>
             pub fn main() {
-                let a = 0;
-                let it = range(0, 100);
+                let sum = 0;
 
-                let a = for v in it {
-                    if a >= 10 {
+                for (a, b) in [(1, 2), (3, 4), (5, 6)] {
+                    if a == 3 {
+                        continue;
+                    }
+
+                    sum = sum + a * b;
+                }
+
+                sum
+            }
+        },
+        32,
+    };
+
+    assert_eq! {
+        rune! { i64 =>
+            pub fn main() {
+                let count = 0;
+
+                for _ in [1, 2, 3] {
+                    count = count + 1;
+                }
+
+                count
+            }
+        },
+        3,
+    };
+
+    assert_vm_error!(
+        r#"
+        pub fn main() {
+            for [a] in [[1], [2, 3]] {}
+        }
+        "#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "pattern did not match");
+        }
+    );
+}
+
+#[test]
+fn test_continue() {
+    assert_eq! {
+        rune! { i64 =>
+            pub fn main() {
+                let n = 0;
+                let sum = 0;
+
+                while n < 10 {
+                    n = n + 1;
+                    let value = n;
+
+                    if value % 2 == 1 {
+                        continue;
+                    }
+
+                    sum = sum + value;
+                }
+
+                sum
+            }
+        },
+        30,
+    };
+
+    assert_eq! {
+        rune! { i64 =>
+            pub fn main() {
+                let n = 0;
+                let sum = 0;
+
+                loop {
+                    n = n + 1;
+
+                    if n > 10 {
                         break;
                     }
 
-                    a = a + 1;
-                };
+                    let value = n;
 
-                a is unit
+                    if value % 2 == 1 {
+                        continue;
+                    }
+
+                    sum = sum + value;
+                }
+
+                sum
             }
         },
-        true,
+        30,
+    };
+
+    assert_eq! {
+        rune! { i64 =>
+            pub fn main() {
+                let sum = 0;
+
+                for n in [1, 2, 3, 4, 5, 6] {
+                    let odd = n % 2 == 1;
+
+                    if odd {
+                        continue;
+                    }
+
+                    sum = sum + n;
+                }
+
+                sum
+            }
+        },
+        12,
+    };
+
+    assert_eq! {
+        rune! { i64 =>
+            pub fn main() {
+                let count = 0;
+
+                'outer: for a in [1, 2, 3] {
+                    for b in [1, 2, 3] {
+                        let c = b;
+
+                        if c > a {
+                            continue 'outer;
+                        }
+
+                        count = count + 1;
+                    }
+                }
+
+                count
+            }
+        },
+        6,
     };
 }
 
@@ -303,6 +516,84 @@ fn test_return() {
     };
 }
 
+#[test]
+fn test_early_return() {
+    assert_eq! {
+        rune! { i64 =>
+            pub fn main() {
+                let n = 0;
+
+                loop {
+                    let step = 2;
+                    n += step;
+
+                    if n > 5 {
+                        return n;
+                    }
+                }
+            }
+        },
+        6,
+    };
+
+    assert_eq! {
+        rune! { () =>
+            pub fn main() {
+                let n = 0;
+
+                while true {
+                    let a = 1;
+                    return;
+                }
+
+                n
+            }
+        },
+        (),
+    };
+}
+
+/// The virtual machine checks that the stack frame is empty when returning, so
+/// this also asserts that every local is correctly cleaned up.
+#[test]
+fn test_return_nested_blocks() {
+    assert_eq! {
+        rune! { i64 =>
+            fn inner(n) {
+                let a = 1;
+
+                {
+                    let b = 2;
+
+                    match n {
+                        0 => {
+                            let c = 3;
+                            return a + b + c;
+                        }
+                        _ => {
+                            let c = 4;
+
+                            if n > 1 {
+                                let d = 5;
+                                return a + b + c + d;
+                            }
+                        }
+                    }
+                }
+
+                a
+            }
+
+            pub fn main() {
+                let x = 10;
+                let y = inner(0) + inner(1) + inner(2);
+                x + y
+            }
+        },
+        10 + 6 + 1 + 12,
+    };
+}
+
 #[test]
 fn test_is() {
     assert_eq! {
@@ -377,10 +668,12 @@ fn test_vec_match() {
         true,
     };
 
-    assert_eq! {
-        rune!(() => pub fn main() { match [] { [a, b] => a + 1 == b } }),
-        (),
-    };
+    assert_vm_error!(
+        r#"pub fn main() { match [] { [a, b] => a + 1 == b } }"#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "pattern did not match");
+        }
+    );
 
     assert_eq! {
         rune!(bool => pub fn main() { match [1, 2] { [a, b] => a + 1 == b, _ => false } }),
@@ -549,6 +842,35 @@ fn test_if_pattern() {
     };
 }
 
+#[test]
+fn test_if_let_option() {
+    assert_eq! {
+        rune! { (i64, i64) =>
+            fn unwrap_or(value, fallback) {
+                if let Some(n) = value {
+                    n
+                } else {
+                    fallback
+                }
+            }
+
+            pub fn main() {
+                (unwrap_or(Some(5), 0), unwrap_or(None, 7))
+            }
+        },
+        (5, 7),
+    };
+
+    // Bindings are only visible inside of the then-block.
+    assert_compile_error! {
+        r#"pub fn main() { if let Some(n) = None { 1 } else { n } }"#,
+        span, MissingLocal { name } => {
+            assert_eq!(name, "n");
+            assert_eq!(span, Span::new(51, 52));
+        }
+    };
+}
+
 #[test]
 fn test_break_label() {
     assert_eq! {
@@ -596,6 +918,93 @@ fn test_string_concat() {
     };
 }
 
+#[test]
+fn test_string_iter() {
+    assert_eq! {
+        rune! { (Vec<char>, Vec<char>, Vec<u8>) =>
+            pub fn main() {
+                let chars = [];
+
+                for c in "abc" {
+                    chars.push(c);
+                }
+
+                let unicode = [];
+
+                for c in "åä".chars() {
+                    unicode.push(c);
+                }
+
+                let bytes = [];
+
+                for b in "abc".bytes() {
+                    bytes.push(b);
+                }
+
+                (chars, unicode, bytes)
+            }
+        },
+        (vec!['a', 'b', 'c'], vec!['å', 'ä'], vec![b'a', b'b', b'c']),
+    };
+}
+
+#[test]
+fn test_string_manipulation() {
+    assert_eq! {
+        rune! { (Vec<String>, usize, String, String) =>
+            pub fn main() {
+                let parts = [];
+
+                for part in "a,b,c".split(",") {
+                    parts.push(part);
+                }
+
+                let count = "a,,b".split(",").len();
+                (parts, count, "  \t hello world \n".trim(), "a-b-c".replace("-", "+"))
+            }
+        },
+        (
+            vec![String::from("a"), String::from("b"), String::from("c")],
+            3,
+            String::from("hello world"),
+            String::from("a+b+c"),
+        ),
+    };
+
+    assert_vm_error!(
+        r#"pub fn main() { "abc".split("") }"#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "cannot split on an empty separator");
+        }
+    );
+}
+
+#[test]
+fn test_string_bytes_conversions() {
+    let (string, bytes, len, invalid) = rune! { (String, runestick::Bytes, usize, bool) =>
+        pub fn main() {
+            let bytes = "hi".as_bytes();
+
+            let string = match String::from_utf8(bytes) {
+                Ok(string) => string,
+                Err(..) => "not utf-8",
+            };
+
+            let invalid = match String::from_utf8(b"\xff") {
+                Ok(..) => false,
+                Err(..) => true,
+            };
+
+            (string, bytes, bytes.len(), invalid)
+        }
+    };
+
+    assert_eq!(string, "hi");
+    assert_eq!(bytes, b"hi"[..]);
+    assert_eq!(len, 2);
+    assert!(invalid);
+}
+
 #[test]
 fn test_template_string() {
     assert_eq! {
@@ -627,6 +1036,61 @@ fn test_template_string() {
     };
 }
 
+#[test]
+fn test_template_string_escaped_braces() {
+    assert_eq! {
+        rune_s! { String => r#"
+            pub fn main() {
+                let x = 42;
+                `a {{b}} ${x} {{${x}}} }}{{`
+            }
+        "#},
+        "a {b} 42 {42} }{",
+    };
+
+    assert_parse_error! {
+        r#"pub fn main() { `a {b` }"#,
+        span, UnescapedTemplateBrace { c: '{' } => {
+            assert_eq!(span, Span::new(19, 20));
+        }
+    };
+
+    assert_parse_error! {
+        r#"pub fn main() { `a ${1}} b` }"#,
+        span, UnescapedTemplateBrace { c: '}' } => {
+            assert_eq!(span, Span::new(23, 24));
+        }
+    };
+}
+
+#[test]
+fn test_template_string_format_types() {
+    assert_eq!(rune_s!(String => r#"pub fn main() { `${255:x}` }"#), "ff");
+
+    assert_eq! {
+        rune_s! { String => r#"
+            pub fn main() {
+                let n = 255;
+                `${n:X} ${n:o} ${n:b} ${-n:x}`
+            }
+        "#},
+        format!("FF 377 11111111 {:x}", -255i64),
+    };
+
+    assert_vm_error!(
+        r#"pub fn main() { `${"hello":x}` }"#,
+        FormatError => {}
+    );
+
+    assert_compile_error! {
+        r#"pub fn main() { `${1:y}` }"#,
+        span, CompileErrorKind::ParseError { error: Unsupported { what } } => {
+            assert_eq!(span, Span::new(21, 22));
+            assert_eq!(what, "template format type");
+        }
+    };
+}
+
 #[test]
 fn test_variants_as_functions() {
     assert_eq! {
@@ -723,6 +1187,51 @@ fn test_complex_field_access() {
     };
 }
 
+#[test]
+fn test_struct_field_order() {
+    // NB: fields written out of declaration order must still be assigned to
+    // the field with the matching name.
+    assert_eq! {
+        rune_s! {
+            (i64, i64, String) => r#"
+            struct Foo { a, b, c }
+
+            pub fn main() {
+                let foo = Foo { c: "c", b: 2, a: 1 };
+                (foo.a, foo.b, foo.c)
+            }
+            "#
+        },
+        (1, 2, String::from("c")),
+    };
+}
+
+#[test]
+fn test_object_shorthand() {
+    assert_eq! {
+        rune! { (i64, i64, i64) =>
+            struct Foo { a, b }
+
+            pub fn main() {
+                let a = 1;
+                let b = 2;
+                let object = #{a, b};
+                let foo = Foo { b, a: 3 };
+                (object.a + object.b, foo.a, foo.b)
+            }
+        },
+        (3, 3, 2),
+    };
+
+    assert_compile_error! {
+        r#"pub fn main() { #{a} }"#,
+        span, MissingLocal { name } => {
+            assert_eq!(name, "a");
+            assert_eq!(span, Span::new(18, 19));
+        }
+    };
+}
+
 #[test]
 fn test_index_get() {
     assert_eq! {
@@ -742,3 +1251,550 @@ fn test_index_get() {
         32,
     };
 }
+
+#[test]
+fn test_shared_context_across_threads() {
+    use std::sync::Arc;
+
+    let context = Arc::new(rune_modules::default_context().unwrap());
+
+    let threads = (0..8i64)
+        .map(|n| {
+            let context = context.clone();
+
+            std::thread::spawn(move || {
+                let (unit, _) = rune::testing::compile_source(
+                    &context,
+                    r#"pub fn main(n) { let out = 0; for v in [1, 2, 3] { out += v * n; } out }"#,
+                )
+                .expect("source should compile");
+
+                let vm = runestick::Vm::new(context, Arc::new(unit));
+                let output = vm.call(&["main"], (n,)).unwrap();
+                i64::from_value(output).unwrap()
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for (n, thread) in threads.into_iter().enumerate() {
+        assert_eq!(thread.join().unwrap(), 6 * n as i64);
+    }
+}
+
+#[test]
+fn test_cancellation() {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    let context = Arc::new(rune_modules::default_context().unwrap());
+    let (unit, _) =
+        rune::testing::compile_source(&context, r#"pub fn main() { while true {} }"#).unwrap();
+
+    let mut vm = runestick::Vm::new(context, Arc::new(unit));
+    let token = vm.cancellation_token();
+
+    let thread = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(10));
+        token.cancel();
+    });
+
+    let error = vm.call(&["main"], ()).unwrap_err();
+    thread.join().unwrap();
+
+    let (kind, _) = error.as_unwound();
+    assert!(matches!(kind, runestick::VmErrorKind::Cancelled));
+}
+
+#[test]
+fn test_instruction_limit() {
+    use std::sync::Arc;
+
+    let context = Arc::new(rune_modules::default_context().unwrap());
+    let (unit, _) =
+        rune::testing::compile_source(&context, r#"pub fn main() { loop {} }"#).unwrap();
+
+    let vm = runestick::Vm::new(context, Arc::new(unit)).with_instruction_limit(100);
+    let mut execution = vm.execute(&["main"], ()).unwrap();
+    let error = execution.complete().unwrap_err();
+
+    let (kind, _) = error.as_unwound();
+    assert!(matches!(
+        kind,
+        runestick::VmErrorKind::BudgetExceeded { limit: 100 }
+    ));
+}
+
+#[test]
+fn test_max_call_depth() {
+    use runestick::FromValue as _;
+    use std::sync::Arc;
+
+    assert_vm_error!(
+        r#"fn recurse(n) { recurse(n + 1) } pub fn main() { recurse(0) }"#,
+        StackOverflow { depth } => {
+            assert_eq!(depth, 10_000);
+        }
+    );
+
+    let context = Arc::new(rune_modules::default_context().unwrap());
+    let (unit, _) = rune::testing::compile_source(
+        &context,
+        r#"fn depth(n) { if n == 0 { 0 } else { depth(n - 1) + 1 } } pub fn main(n) { depth(n) }"#,
+    )
+    .unwrap();
+    let unit = Arc::new(unit);
+
+    let vm = runestick::Vm::new(context.clone(), unit.clone()).with_max_call_depth(10);
+    let error = vm.call(&["main"], (20,)).unwrap_err();
+
+    let (kind, _) = error.as_unwound();
+    assert!(matches!(
+        kind,
+        runestick::VmErrorKind::StackOverflow { depth: 10 }
+    ));
+
+    let vm = runestick::Vm::new(context, unit).with_max_call_depth(10);
+    let output = vm.call(&["main"], (5,)).unwrap();
+    assert_eq!(i64::from_value(output).unwrap(), 5);
+}
+
+#[test]
+fn test_trace_hook() {
+    use runestick::{Inst, InstOp, InstValue};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    let context = Arc::new(rune_modules::default_context().unwrap());
+    let (unit, _) = rune::testing::compile_source(&context, r#"pub fn main(a) { a + 2 }"#).unwrap();
+
+    let trace = Rc::new(RefCell::new(Vec::new()));
+
+    let mut vm = runestick::Vm::new(context, Arc::new(unit));
+
+    let hook_trace = trace.clone();
+    vm.set_trace_hook(move |ip, inst, stack| {
+        hook_trace.borrow_mut().push((ip, *inst, stack.len()));
+    });
+
+    let output = vm.call(&["main"], (1i64,)).unwrap();
+    assert!(matches!(output, runestick::Value::Integer(3)));
+
+    let trace = trace.borrow();
+    let insts = trace
+        .iter()
+        .map(|(_, inst, _)| inst.to_string())
+        .collect::<Vec<_>>();
+    let expected = vec![
+        Inst::Copy { offset: 0 }.to_string(),
+        Inst::Push {
+            value: InstValue::Integer(2),
+        }
+        .to_string(),
+        Inst::Op { op: InstOp::Add }.to_string(),
+        Inst::Clean { count: 1 }.to_string(),
+        Inst::Return.to_string(),
+    ];
+
+    assert_eq!(insts, expected);
+    assert_eq!(
+        trace.iter().map(|(ip, _, n)| (*ip, *n)).collect::<Vec<_>>(),
+        vec![(0, 1), (1, 2), (2, 3), (3, 2), (4, 1)]
+    );
+}
+
+#[test]
+fn test_live_slots() {
+    use runestick::{Span, TypeInfo, Vm};
+    use std::sync::Arc;
+
+    let context = Arc::new(rune_modules::default_context().unwrap());
+    let (unit, _) = rune::testing::compile_source(
+        &context,
+        r#"pub fn main() { let a = [1]; let b = [2]; let c = [3]; (a, c) }"#,
+    )
+    .unwrap();
+
+    let mut vm = Vm::new(context, Arc::new(unit));
+    vm.track_allocations();
+
+    // NB: clones of the virtual machine share allocation tracking.
+    let output = vm.clone().call(&["main"], ()).unwrap();
+
+    let live = vm
+        .live_slots()
+        .into_iter()
+        .map(|slot| (slot.span, slot.type_info.to_string()))
+        .collect::<Vec<_>>();
+
+    let vec = TypeInfo::StaticType(runestick::VEC_TYPE).to_string();
+    let tuple = TypeInfo::StaticType(runestick::TUPLE_TYPE).to_string();
+
+    assert_eq!(
+        live,
+        vec![
+            (Some(Span::new(24, 27)), vec.clone()),
+            (Some(Span::new(50, 53)), vec),
+            (Some(Span::new(55, 61)), tuple),
+        ]
+    );
+
+    drop(output);
+    assert!(vm.live_slots().is_empty());
+}
+
+#[test]
+fn test_profile() {
+    use runestick::Vm;
+    use std::sync::Arc;
+
+    let context = Arc::new(rune_modules::default_context().unwrap());
+    let (unit, _) = rune::testing::compile_source(
+        &context,
+        r#"pub fn main() { let n = 0; while n < 10 { n += 1; } n }"#,
+    )
+    .unwrap();
+
+    let mut vm = Vm::new(context, Arc::new(unit));
+    assert!(vm.take_profile().is_empty());
+    vm.enable_profiling();
+
+    // NB: clones of the virtual machine share the profile.
+    let output = vm.clone().call(&["main"], ()).unwrap();
+    assert_eq!(output.into_integer().unwrap(), 10);
+
+    let profile = vm.take_profile();
+    assert!(profile.get("Jump").copied().unwrap_or_default() >= 10);
+    assert!(profile.get("Op").copied().unwrap_or_default() >= 10);
+    assert!(vm.take_profile().is_empty());
+}
+
+#[test]
+fn test_stack_capacity() {
+    use runestick::Vm;
+    use std::sync::Arc;
+
+    let context = Arc::new(rune_modules::default_context().unwrap());
+    let (unit, _) = rune::testing::compile_source(
+        &context,
+        r#"
+        fn count(n) { if n == 0 { 0 } else { 1 + count(n - 1) } }
+        pub fn main() { count(100) }
+        "#,
+    )
+    .unwrap();
+
+    let unit = Arc::new(unit);
+
+    // Run `main` one instruction at a time, returning the high-water mark of
+    // the stack and whether its capacity changed during the run.
+    let run = |vm: Vm| {
+        let capacity = vm.stack().capacity();
+        let mut execution = vm.execute(&["main"], ()).unwrap();
+        let mut reallocated = false;
+
+        let output = loop {
+            let output = execution.step().unwrap();
+            reallocated |= execution.vm().unwrap().stack().capacity() != capacity;
+
+            if let Some(output) = output {
+                break output;
+            }
+        };
+
+        assert!(matches!(output, runestick::Value::Integer(100)));
+        (
+            execution.vm().unwrap().stack().high_water_mark(),
+            reallocated,
+        )
+    };
+
+    let (high_water_mark, reallocated) = run(Vm::new(context.clone(), unit.clone()));
+    assert!(high_water_mark > 100);
+    assert!(reallocated);
+
+    let vm = Vm::new(context, unit).with_stack_capacity(high_water_mark);
+    assert!(vm.stack().capacity() >= high_water_mark);
+    assert_eq!(run(vm), (high_water_mark, false));
+}
+
+#[test]
+fn test_stack_underflow() {
+    use runestick::{Call, Hash, Inst, InstValue, Unit, UnitFn, Vm, VmErrorKind};
+    use std::sync::Arc;
+
+    let run = |inst: Inst| {
+        let instructions = vec![
+            Inst::Push {
+                value: InstValue::Integer(1),
+            },
+            inst,
+            Inst::Return,
+        ];
+
+        let main = UnitFn::Offset {
+            offset: 0,
+            call: Call::Immediate,
+            args: 0,
+        };
+
+        let unit = Unit::new(
+            instructions,
+            vec![(Hash::type_hash(&["main"]), main)]
+                .into_iter()
+                .collect(),
+            Default::default(),
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+            Default::default(),
+            Default::default(),
+            None,
+        );
+
+        let context = Arc::new(runestick::Context::new());
+        let vm = Vm::new(context, Arc::new(unit));
+        let error = vm.execute(&["main"], ()).unwrap().complete().unwrap_err();
+        let (kind, _) = error.as_unwound();
+        matches!(kind, VmErrorKind::StackError { .. })
+    };
+
+    // NB: a miscompiled or corrupted unit must not be able to pop beyond the
+    // current stack frame.
+    assert!(run(Inst::PopN { count: 10 }));
+    assert!(run(Inst::Clean { count: 10 }));
+    assert!(run(Inst::Copy { offset: 10 }));
+}
+
+#[test]
+fn test_backtrace() {
+    use runestick::{Item, Span};
+    use std::sync::Arc;
+
+    let source = r#"fn div(a, b) { a / b } pub fn main() { div(1, 0) }"#;
+
+    let context = Arc::new(rune_modules::default_context().unwrap());
+    let (unit, _) = rune::testing::compile_source(&context, source).unwrap();
+
+    let vm = runestick::Vm::new(context, Arc::new(unit));
+    let error = vm.execute(&["main"], ()).unwrap().complete().unwrap_err();
+
+    let (kind, _) = error.as_unwound();
+    assert!(matches!(kind, runestick::VmErrorKind::DivideByZero));
+
+    let backtrace = error.backtrace();
+    assert_eq!(backtrace.len(), 2);
+
+    let op = source.find('/').unwrap();
+    assert_eq!(backtrace[0].item, Some(Item::of(&["div"])));
+    assert_eq!(backtrace[0].span, Some(Span::new(op, op + 1)));
+
+    let call = source.find("div(1, 0)").unwrap();
+    assert_eq!(backtrace[1].item, Some(Item::of(&["main"])));
+    assert_eq!(backtrace[1].span, Some(Span::new(call, call + 9)));
+}
+
+#[test]
+fn test_unit_bytes_roundtrip() {
+    use runestick::{FromValue as _, Unit, UnitFormatError};
+    use std::sync::Arc;
+
+    let context = Arc::new(rune_modules::default_context().unwrap());
+    let (unit, _) = rune::testing::compile_source(
+        &context,
+        r#"pub fn main(n) { let o = #{"a": n, "b": b"bytes"}; `${o.a} and ${o.b.len()}` }"#,
+    )
+    .unwrap();
+
+    let bytes = unit.to_bytes().unwrap();
+    let unit = Unit::from_bytes(&bytes).unwrap();
+
+    let vm = runestick::Vm::new(context, Arc::new(unit));
+    let output = String::from_value(vm.call(&["main"], (42,)).unwrap()).unwrap();
+    assert_eq!(output, "42 and 5");
+
+    assert!(matches!(
+        Unit::from_bytes(b"garbage"),
+        Err(UnitFormatError::BadMagic)
+    ));
+
+    // NB: the version is stored as a length-prefixed string after the magic.
+    let mut bytes = bytes;
+    let version = env!("CARGO_PKG_VERSION").as_bytes();
+    let at = 4 + 8;
+    assert_eq!(&bytes[at..at + version.len()], version);
+    bytes[at] = b'9';
+
+    assert!(matches!(
+        Unit::from_bytes(&bytes),
+        Err(UnitFormatError::IncompatibleVersion { .. })
+    ));
+}
+
+#[test]
+fn test_nested_conversion_path() {
+    use std::sync::Arc;
+
+    let context = Arc::new(rune_modules::default_context().unwrap());
+
+    let error = rune::testing::run::<_, _, Vec<(i64, String)>>(
+        &context,
+        &["main"],
+        (),
+        r#"pub fn main() { [(1, "a"), (2, "b"), (3, 4)] }"#,
+    )
+    .unwrap_err();
+
+    let error = match error {
+        rune::testing::RunError::VmError(error) => error,
+        error => panic!("expected vm error, got {:?}", error),
+    };
+
+    assert_eq!(
+        error.to_string(),
+        "at index 2, field 1: expected `String`, but found `integer`"
+    );
+}
+
+#[test]
+fn test_bad_argument_count() {
+    assert_vm_error!(
+        r#"
+        fn add(a, b) { a + b }
+        pub fn main() { add(1, 2, 3) }
+        "#,
+        BadFunctionArgumentCount { function, actual, expected } => {
+            assert_eq!(function, runestick::Item::of(&["add"]));
+            assert_eq!(actual, 3);
+            assert_eq!(expected, 2);
+        }
+    );
+}
+
+#[test]
+fn test_context_conflicting_function() {
+    use runestick::{ContextError, FromValue as _, Module};
+    use std::sync::Arc;
+
+    let mut context = rune_modules::default_context().unwrap();
+
+    let mut module = Module::new(&["std"]);
+    module
+        .function(&["string", "String", "new"], || String::from("custom"))
+        .unwrap();
+
+    match context.install(&module).unwrap_err() {
+        ContextError::ConflictingFunction {
+            current_module,
+            existing_module,
+            ..
+        } => {
+            assert_eq!(current_module.to_string(), "std");
+            assert_eq!(existing_module.to_string(), "std::string");
+        }
+        error => panic!("unexpected error: {}", error),
+    }
+
+    module.allow_overrides();
+    context.install(&module).unwrap();
+
+    let context = Arc::new(context);
+    let (unit, _) =
+        rune::testing::compile_source(&context, r#"pub fn main() { String::new() }"#).unwrap();
+
+    let vm = runestick::Vm::new(context, Arc::new(unit));
+    let output = vm.execute(&["main"], ()).unwrap().complete().unwrap();
+    assert_eq!(String::from_value(output).unwrap(), "custom");
+}
+
+#[test]
+fn test_panic_message() {
+    use std::sync::Arc;
+
+    assert_vm_error!(
+        r#"pub fn main() { panic("boom") }"#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "boom");
+        }
+    );
+
+    let context = Arc::new(rune_modules::default_context().unwrap());
+
+    let error = rune::testing::run::<_, _, ()>(
+        &context,
+        &["main"],
+        (),
+        r#"pub fn main() { panic("boom: `a` {b}") }"#,
+    )
+    .unwrap_err();
+
+    let error = match error {
+        rune::testing::RunError::VmError(error) => error,
+        error => panic!("expected vm error, got {:?}", error),
+    };
+
+    assert!(error.to_string().contains("boom: `a` {b}"));
+}
+
+#[test]
+fn test_assert_functions() {
+    rune!(() => pub fn main() { assert(true); assert_eq(1 + 1, 2); assert_eq([1, "a"], [1, "a"]) });
+
+    assert_vm_error!(
+        r#"pub fn main() { assert(1 == 2) }"#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "assertion failed");
+        }
+    );
+
+    assert_vm_error!(
+        r#"pub fn main() { assert_eq(1, 2) }"#,
+        Panic { reason } => {
+            assert_eq!(
+                reason.to_string(),
+                "assertion failed (left == right): left: 1, right: 2"
+            );
+        }
+    );
+
+    assert_vm_error!(
+        r#"pub fn main() { assert_eq("a", [1]) }"#,
+        Panic { reason } => {
+            assert_eq!(
+                reason.to_string(),
+                "assertion failed (left == right): left: \"a\", right: [1]"
+            );
+        }
+    );
+}
+
+#[test]
+fn test_number_parse() {
+    assert_eq! {
+        rune!(Option<i64> => pub fn main() {
+            match std::int::parse("42") { Ok(n) => Some(n), Err(_) => None }
+        }),
+        Some(42),
+    };
+
+    assert_eq! {
+        rune!((bool, bool, bool) => pub fn main() {
+            (std::int::parse("x").is_err(), std::int::parse(" 42").is_err(), std::int::parse("42\n").is_err())
+        }),
+        (true, true, true),
+    };
+
+    assert_eq! {
+        rune!(Option<f64> => pub fn main() {
+            match std::float::parse("4.5") { Ok(n) => Some(n), Err(_) => None }
+        }),
+        Some(4.5),
+    };
+
+    assert_eq! {
+        rune!((bool, bool) => pub fn main() {
+            (std::float::parse("x").is_err(), std::float::parse(" 4.5 ").is_err())
+        }),
+        (true, true),
+    };
+}
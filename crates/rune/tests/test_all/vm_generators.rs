@@ -55,3 +55,55 @@ fn test_resume() {
         6,
     };
 }
+
+#[test]
+fn test_call_generator() {
+    use std::sync::Arc;
+
+    let context = Arc::new(rune_modules::default_context().unwrap());
+    let (unit, _) = rune::testing::compile_source(
+        &context,
+        r#"
+        pub fn numbers(n) {
+            for i in 0..n {
+                yield i * 2;
+            }
+
+            yield "not a number";
+        }
+        "#,
+    )
+    .unwrap();
+
+    let vm = runestick::Vm::new(context, Arc::new(unit));
+    let mut iter = vm
+        .call_generator::<i64, _, _>(&["numbers"], (3i64,))
+        .unwrap();
+
+    assert_eq!(iter.next().unwrap().unwrap(), 0);
+    assert_eq!(iter.next().unwrap().unwrap(), 2);
+    assert_eq!(iter.next().unwrap().unwrap(), 4);
+
+    let error = iter.next().unwrap().unwrap_err();
+    let (kind, _) = error.as_unwound();
+    assert!(matches!(kind, runestick::VmErrorKind::Expected { .. }));
+
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn test_call_generator_missing() {
+    use std::sync::Arc;
+
+    let context = Arc::new(rune_modules::default_context().unwrap());
+    let (unit, _) = rune::testing::compile_source(&context, r#"pub fn main() { 42 }"#).unwrap();
+
+    let vm = runestick::Vm::new(context, Arc::new(unit));
+    let error = vm
+        .call_generator::<i64, _, _>(&["numbers"], ())
+        .unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        runestick::VmErrorKind::MissingEntry { .. }
+    ));
+}
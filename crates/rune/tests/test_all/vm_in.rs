@@ -0,0 +1,68 @@
+use rune::testing::*;
+
+#[test]
+fn test_in() {
+    assert_eq! {
+        rune! { (bool, bool, bool, bool, bool, bool) =>
+            pub fn main() {
+                (
+                    2 in [1, 2, 3],
+                    4 in [1, 2, 3],
+                    "b" in (1, "b"),
+                    "a" in #{a: 1},
+                    "b" in #{a: 1},
+                    !("ell" in "hello"),
+                )
+            }
+        },
+        (true, false, true, true, false, false),
+    };
+
+    assert_eq! {
+        rune! { (bool, bool, bool) =>
+            pub fn main() {
+                let needle = String::from_str("ll");
+
+                (needle in "hello", 'h' in "hello", 1 + 1 in [2] && true)
+            }
+        },
+        (true, true, true),
+    };
+}
+
+#[test]
+fn test_in_contains_fn() {
+    assert_eq! {
+        rune! { (bool, bool) =>
+            struct Evens;
+
+            impl Evens {
+                fn contains(self, n) {
+                    n % 2 == 0
+                }
+            }
+
+            pub fn main() {
+                (2 in Evens, 3 in Evens)
+            }
+        },
+        (true, false),
+    };
+
+    assert_vm_error!(
+        r#"pub fn main() { 1 in 2 }"#,
+        UnsupportedBinaryOperation { op, .. } => {
+            assert_eq!(op, "in");
+        }
+    );
+}
+
+#[test]
+fn test_in_precedence() {
+    assert_parse_error! {
+        r#"pub fn main() { 1 in [1] == true }"#,
+        span, PrecedenceGroupRequired => {
+            assert_eq!(span, Span::new(16, 24));
+        }
+    };
+}
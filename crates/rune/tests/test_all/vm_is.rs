@@ -20,3 +20,21 @@ fn test_binop_override() {
         (true, false, false, true),
     };
 }
+
+#[test]
+fn test_is_builtin_types() {
+    assert_eq! {
+        rune! { (bool, bool, bool, bool, bool) =>
+            pub fn main() {
+                (
+                    1 is int,
+                    1 is not String,
+                    "hello" is String,
+                    "hello" is not int,
+                    2.0 is float,
+                )
+            }
+        },
+        (true, true, true, true, true),
+    };
+}
@@ -73,3 +73,50 @@ fn test_byte_string_literals() {
         b"a b"[..]
     );
 }
+
+#[test]
+fn test_float_to_string() {
+    assert_eq! {
+        rune_s! { Vec<String> => r#"
+            use std::float;
+
+            pub fn main() {
+                let out = [];
+
+                for v in [0.1, 0.1 + 0.2, 1.0 / 3.0, 0.0 - 2.5, 1.0, 123456789.125] {
+                    let s = v.to_string();
+                    assert_eq!(float::parse(s)?, v);
+                    assert_eq!(`${v}`, s);
+                    out.push(s);
+                }
+
+                out
+            }
+        "#},
+        vec!["0.1", "0.30000000000000004", "0.3333333333333333", "-2.5", "1.0", "123456789.125"],
+    };
+
+    assert_eq! {
+        rune_s! { (String, String, String, bool, bool, bool) => r#"
+            use std::float;
+
+            pub fn main() {
+                let nan = 0.0 / 0.0;
+                let inf = 1.0 / 0.0;
+                let neg_inf = (0.0 - 1.0) / 0.0;
+
+                let parsed_nan = float::parse(nan.to_string())?;
+
+                (
+                    nan.to_string(),
+                    inf.to_string(),
+                    `${neg_inf}`,
+                    parsed_nan != parsed_nan,
+                    float::parse(inf.to_string())? == inf,
+                    float::parse(neg_inf.to_string())? == neg_inf,
+                )
+            }
+        "#},
+        ("NaN".to_owned(), "inf".to_owned(), "-inf".to_owned(), true, true, true),
+    };
+}
@@ -1,3 +1,5 @@
+use rune::testing::*;
+
 #[test]
 fn test_path_type_match() {
     assert_eq! {
@@ -95,3 +97,195 @@ fn test_struct_matching() {
         3,
     };
 }
+
+#[test]
+fn test_unit_matching() {
+    assert_eq! {
+        rune! { i64 =>
+            struct Empty;
+
+            pub fn main() {
+                let e = Empty;
+
+                match e {
+                    Empty => 1,
+                    _ => 0,
+                }
+            }
+        },
+        1,
+    };
+
+    assert_eq! {
+        rune! { i64 =>
+            enum Status { Active, Inactive }
+
+            fn code(status) {
+                match status {
+                    Status::Active => 1,
+                    Status::Inactive => 2,
+                    _ => 0,
+                }
+            }
+
+            pub fn main() {
+                code(Status::Active) * 10 + code(Status::Inactive)
+            }
+        },
+        12,
+    };
+}
+
+#[test]
+fn test_literal_and_tuple_matching() {
+    assert_eq! {
+        rune! { i64 =>
+            fn classify(n) {
+                match n {
+                    0 => 10,
+                    1 => 20,
+                    _ => 30,
+                }
+            }
+
+            pub fn main() {
+                classify(0) + classify(1) + classify(42)
+            }
+        },
+        60,
+    };
+
+    assert_eq! {
+        rune! { i64 =>
+            pub fn main() {
+                match (1, 2) {
+                    (2, b) => b,
+                    (a, b) => a + b * 10,
+                }
+            }
+        },
+        21,
+    };
+}
+
+#[test]
+fn test_unmatched_pattern() {
+    assert_vm_error!(
+        r#"
+        pub fn main() {
+            match 3 { 1 => true, 2 => false }
+        }
+        "#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "pattern did not match");
+        }
+    );
+}
+
+#[test]
+fn test_float_patterns() {
+    assert_compile_error! {
+        r#"pub fn main() { match 1.0 { 1.0 => true, _ => false } }"#,
+        span, MatchFloatInPattern => {
+            assert_eq!(span, Span::new(28, 31));
+        }
+    };
+
+    assert_compile_error! {
+        r#"pub fn main() { match 1.0 { -1.0 => true, _ => false } }"#,
+        span, MatchFloatInPattern => {
+            assert_eq!(span, Span::new(28, 32));
+        }
+    };
+
+    // NB: guards are used to match floats instead, and `NaN` never compares
+    // equal to anything.
+    assert_eq! {
+        rune! { (i64, i64) =>
+            pub fn main() {
+                let nan = 0.0 / 0.0;
+                let a = match nan { x if x == 1.0 => 1, _ => 2 };
+                let b = match 1.0 { x if x == 1.0 => 1, _ => 2 };
+                (a, b)
+            }
+        },
+        (2, 1),
+    };
+}
+
+#[test]
+fn test_match_sequence_length() {
+    assert_eq! {
+        rune! { i64 =>
+            pub fn main() {
+                match (1, 2, 3) {
+                    (a, b) => 1,
+                    (a, b, ..) => 2,
+                    _ => 3,
+                }
+            }
+        },
+        2,
+    };
+
+    assert_eq! {
+        rune! { i64 =>
+            pub fn main() {
+                match [1, 2, 3] {
+                    [a, b] => 1,
+                    [a, b, ..] => 2,
+                    _ => 3,
+                }
+            }
+        },
+        2,
+    };
+
+    assert_eq! {
+        rune! { i64 =>
+            pub fn main() {
+                match (1,) {
+                    (a, b, ..) => 1,
+                    _ => 2,
+                }
+            }
+        },
+        2,
+    };
+
+    let (unit, _) =
+        assert_parse!(r#"pub fn main(t) { match t { (a, b) => 1, (a, b, ..) => 2, _ => 3 } }"#);
+
+    let flags = unit
+        .iter_instructions()
+        .filter_map(|inst| match inst {
+            runestick::Inst::MatchSequence { len, exact, .. } => Some((len, exact)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(flags, vec![(2, true), (2, false)]);
+}
+
+#[test]
+fn test_enum_variant_match() {
+    assert_eq! {
+        rune! { i64 =>
+            enum Shape { Circle(r), Square(s), Rect { w, h }, Empty }
+
+            fn area(shape) {
+                match shape {
+                    Shape::Circle(r) => 3 * r * r,
+                    Shape::Square(s) => s * s,
+                    Shape::Rect { w, h } => w * h,
+                    Shape::Empty => 0,
+                }
+            }
+
+            pub fn main() {
+                area(Shape::Circle(2)) + area(Shape::Square(3)) + area(Shape::Rect { w: 2, h: 5 }) + area(Shape::Empty)
+            }
+        },
+        12 + 9 + 10,
+    };
+}
@@ -0,0 +1,131 @@
+use rune::testing::*;
+
+#[test]
+fn test_object_spread() {
+    assert_eq! {
+        rune!((i64, i64, i64, i64) => pub fn main() {
+            let a = #{ x: 1, y: 2 };
+            let b = #{ ..a, y: 3, z: 9 };
+            (a.y, b.x, b.y, b.z)
+        }),
+        (2, 1, 3, 9),
+    };
+
+    assert_eq! {
+        rune!(usize => pub fn main() {
+            let a = #{ x: 1 };
+            let b = #{ ..a };
+            b.x = 2;
+            a.x + b.x + b.len()
+        }),
+        4,
+    };
+}
+
+#[test]
+fn test_struct_spread() {
+    assert_eq! {
+        rune!((i64, i64, i64) => struct Point { x, y, z }
+            pub fn main() {
+                let a = Point { x: 1, y: 2, z: 3 };
+                let b = Point { ..a, z: 9 };
+                (b.x, b.y, b.z)
+            }
+        ),
+        (1, 2, 9),
+    };
+}
+
+#[test]
+fn test_spread_errors() {
+    assert_vm_error!(
+        r#"pub fn main() { let a = [1, 2]; #{ ..a, z: 9 } }"#,
+        UnsupportedSpread { target, actual } => {
+            assert_eq!(target.to_string(), "Object");
+            assert_eq!(actual.to_string(), "Vec");
+        }
+    );
+
+    assert_vm_error!(
+        r#"struct A { x } struct B { x } pub fn main() { let a = A { x: 1 }; B { ..a } }"#,
+        UnsupportedSpread { target, actual } => {
+            assert_eq!(target.to_string(), "B");
+            assert_eq!(actual.to_string(), "A");
+        }
+    );
+
+    assert_compile_error! {
+        r#"pub fn main() { let a = #{}; #{ x: 1, ..a } }"#,
+        span, ObjectSpreadNotFirst => {
+            assert_eq!(span, Span::new(38, 41));
+        }
+    };
+
+    assert_compile_error! {
+        r#"struct A { x } pub fn main() { let a = A { x: 1 }; A { ..a, y: 2 } }"#,
+        span, LitObjectNotField { field, .. } => {
+            assert_eq!(span, Span::new(60, 61));
+            assert_eq!(field.as_ref(), "y");
+        }
+    };
+}
+
+#[test]
+fn test_object_key_order() {
+    let expected = vec!["zeta", "alpha", "mid", "beta", "gamma"];
+
+    for _ in 0..10 {
+        let keys = rune!(Vec<String> => pub fn main() {
+            let o = #{ zeta: 1, alpha: 2, mid: 3 };
+            o.insert("beta", 4);
+            o["gamma"] = 5;
+
+            let keys = [];
+
+            for (key, _) in o {
+                keys.push(key);
+            }
+
+            keys
+        });
+
+        assert_eq!(keys, expected);
+    }
+}
+
+#[test]
+fn test_struct_field_access() {
+    let out = rune!((i64, i64) => struct Point { x, y }
+
+        pub fn main() {
+            let p = Point { x: 1, y: 2 };
+            p.x += 10;
+            (p.x, p.y)
+        }
+    );
+
+    assert_eq!(out, (11, 2));
+}
+
+#[test]
+fn test_object_keys_values() {
+    let out = rune!((Vec<String>, Vec<i64>, Vec<(String, i64)>) => pub fn main() {
+        let o = #{ b: 1, a: 2 };
+        let pairs = [];
+
+        for (k, v) in o.iter() {
+            pairs.push((k, v));
+        }
+
+        (o.keys(), o.values(), pairs)
+    });
+
+    assert_eq!(
+        out,
+        (
+            vec![String::from("b"), String::from("a")],
+            vec![1, 2],
+            vec![(String::from("b"), 1), (String::from("a"), 2)],
+        )
+    );
+}
@@ -28,3 +28,33 @@ fn test_option() {
         2,
     };
 }
+
+#[test]
+fn test_option_combinators() {
+    assert_eq! {
+        rune!(Option<i64> => pub fn main() { Some(2).map(|x| x + 1) }),
+        Some(3),
+    };
+
+    assert_eq! {
+        rune!(Option<i64> => pub fn main() { None.map(|x| x + 1) }),
+        None,
+    };
+
+    assert_eq! {
+        rune!(i64 => pub fn main() { None.unwrap_or(0) }),
+        0,
+    };
+
+    assert_eq! {
+        rune!((bool, bool) => pub fn main() { (Some(1).is_some(), None.is_some()) }),
+        (true, false),
+    };
+
+    assert_eq! {
+        rune!((Result<i64, String>, Result<i64, String>) => pub fn main() {
+            (Some(1).ok_or("missing"), None.ok_or("missing"))
+        }),
+        (Ok(1), Err(String::from("missing"))),
+    };
+}
@@ -1,3 +1,5 @@
+use rune::testing::*;
+
 #[test]
 fn test_ignore_binding() {
     assert_eq! {
@@ -45,3 +47,46 @@ fn test_match_binding() {
         true,
     };
 }
+
+#[test]
+fn test_let_destructuring() {
+    assert_eq! {
+        rune! { (i64, i64, i64) =>
+            pub fn main() {
+                let (a, b) = (1, 2);
+                let [c, (d, e)] = [3, (4, 5)];
+                let #{f, g} = #{f: 6, g: 7};
+                (a + b, c + d + e, f + g)
+            }
+        },
+        (3, 12, 13),
+    };
+
+    assert_eq! {
+        rune! { i64 =>
+            pub fn main() {
+                let [a, ..] = [1, 2, 3];
+                let (b, ..) = (4, 5, 6);
+                a + b
+            }
+        },
+        5,
+    };
+}
+
+#[test]
+fn test_let_destructuring_mismatch() {
+    assert_vm_error!(
+        r#"pub fn main() { let (a, b) = (1, 2, 3); }"#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "pattern did not match");
+        }
+    );
+
+    assert_vm_error!(
+        r#"pub fn main() { let [a, b, c] = [1, 2]; }"#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "pattern did not match");
+        }
+    );
+}
@@ -0,0 +1,134 @@
+use rune::testing::*;
+
+#[test]
+fn test_range_iter() {
+    assert_eq! {
+        rune! { (i64, i64, i64, i64) =>
+            fn count(range) {
+                let n = 0;
+
+                for _ in range {
+                    n += 1;
+                }
+
+                n
+            }
+
+            pub fn main() {
+                (count(0..5), count(0..=5), count(5..0), count(5..=5))
+            }
+        },
+        (5, 6, 0, 1),
+    };
+
+    assert_eq! {
+        rune! { Vec<i64> =>
+            pub fn main() {
+                let n = 2;
+                let out = [];
+
+                for i in n - 1..=n * 2 {
+                    out.push(i);
+                }
+
+                for i in (0..3).rev() {
+                    out.push(i);
+                }
+
+                out
+            }
+        },
+        vec![1, 2, 3, 4, 2, 1, 0],
+    };
+}
+
+#[test]
+fn test_range_contains() {
+    assert_eq! {
+        rune! { (bool, bool, bool, bool, bool) =>
+            pub fn main() {
+                let range = 0..3;
+
+                (
+                    range.contains(2),
+                    range.contains(3),
+                    (0..=3).contains(3),
+                    2 in 0..3 && true,
+                    (5..0).is_empty(),
+                )
+            }
+        },
+        (true, false, true, true, true),
+    };
+
+    assert_eq! {
+        rune_s! { String => r#"pub fn main() { `${0..3} ${1..=2}` }"# },
+        "0..3 1..=2",
+    };
+}
+
+#[test]
+fn test_range_bad_bounds() {
+    assert_vm_error!(
+        r#"pub fn main() { 0..=1.5 }"#,
+        UnsupportedBinaryOperation { op, .. } => {
+            assert_eq!(op, "..=");
+        }
+    );
+}
+
+#[test]
+fn test_range_slice() {
+    assert_eq!(
+        rune!(Vec<i64> => pub fn main() { let a = [1, 2, 3, 4, 5]; a[1..3] }),
+        vec![2, 3]
+    );
+    assert_eq!(
+        rune!(Vec<i64> => pub fn main() { let a = [1, 2, 3, 4, 5]; a[1..=3] }),
+        vec![2, 3, 4]
+    );
+    assert_eq!(
+        rune!(Vec<i64> => pub fn main() { let a = [1, 2, 3, 4, 5]; a[0..5] }),
+        vec![1, 2, 3, 4, 5]
+    );
+    assert_eq!(
+        rune!(Vec<i64> => pub fn main() { let a = [1, 2, 3, 4, 5]; a[2..2] }),
+        Vec::<i64>::new()
+    );
+    assert_eq!(
+        rune!(i64 => pub fn main() { let a = [1, 2, 3, 4, 5]; let b = a[1..3]; b.push(10); a.len() }),
+        5
+    );
+}
+
+#[test]
+fn test_range_slice_errors() {
+    assert_vm_error!(
+        r#"pub fn main() { let a = [1, 2, 3, 4, 5]; a[3..6] }"#,
+        SliceOutOfBounds { range, len, .. } => {
+            assert_eq!(range.to_string(), "3..6");
+            assert_eq!(len, 5);
+        }
+    );
+
+    assert_vm_error!(
+        r#"pub fn main() { let a = [1, 2, 3, 4, 5]; a[0..=5] }"#,
+        SliceOutOfBounds { len, .. } => {
+            assert_eq!(len, 5);
+        }
+    );
+
+    assert_vm_error!(
+        r#"pub fn main() { let a = [1, 2, 3, 4, 5]; a[-1..2] }"#,
+        SliceOutOfBounds { range, .. } => {
+            assert_eq!(range.start, -1);
+        }
+    );
+
+    assert_vm_error!(
+        r#"pub fn main() { let a = [1, 2, 3, 4, 5]; a[3..1] }"#,
+        SliceReversed { range, .. } => {
+            assert_eq!(range.to_string(), "3..1");
+        }
+    );
+}
@@ -21,3 +21,23 @@ fn test_result() {
         1,
     };
 }
+
+#[test]
+fn test_result_combinators() {
+    assert_eq! {
+        rune!((Result<i64, i64>, Result<i64, i64>) => pub fn main() {
+            (Ok(2).map(|x| x * 2), Err(2).map(|x| x * 2))
+        }),
+        (Ok(4), Err(2)),
+    };
+
+    assert_eq! {
+        rune!((i64, i64) => pub fn main() { (Ok(1).unwrap_or(0), Err(1).unwrap_or(0)) }),
+        (1, 0),
+    };
+
+    assert_eq! {
+        rune!((Option<i64>, Option<i64>) => pub fn main() { (Ok(1).ok(), Err(1).ok()) }),
+        (Some(1), None),
+    };
+}
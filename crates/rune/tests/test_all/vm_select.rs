@@ -0,0 +1,139 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Run `main` with the `ready` and `pending` async functions installed in
+/// the context.
+fn run_select(source: &str) -> runestick::Result<i64> {
+    run_select_with(source, |_| ())
+}
+
+/// Run `main` like [run_select], letting `configure` set up the virtual
+/// machine first.
+fn run_select_with<F>(source: &str, configure: F) -> runestick::Result<i64>
+where
+    F: FnOnce(&mut runestick::Vm),
+{
+    let mut module = runestick::Module::default();
+    module.async_function(&["ready"], |n: i64| async move { n })?;
+    module.async_function(&["pending"], std::future::pending::<i64>)?;
+
+    let mut context = rune_modules::default_context()?;
+    context.install(&module)?;
+    let context = Arc::new(context);
+
+    let (unit, _) = rune::testing::compile_source(&context, source).expect("source should compile");
+
+    let mut vm = runestick::Vm::new(context, Arc::new(unit));
+    configure(&mut vm);
+    let output = rune::testing::block_on(vm.async_call(&["main"], ()))?;
+    Ok(runestick::FromValue::from_value(output)?)
+}
+
+#[test]
+fn test_select_dispatches_to_completed_branch() -> runestick::Result<()> {
+    let source = |a: &str, b: &str, c: &str| {
+        format!(
+            r#"
+            pub async fn main() {{
+                let a = {};
+                let b = {};
+                let c = {};
+
+                select {{
+                    n = a => n + 10,
+                    n = b => n + 20,
+                    n = c => n + 30,
+                }}
+            }}
+            "#,
+            a, b, c
+        )
+    };
+
+    assert_eq!(
+        run_select(&source("ready(1)", "pending()", "pending()"))?,
+        11
+    );
+    assert_eq!(
+        run_select(&source("pending()", "ready(2)", "pending()"))?,
+        22
+    );
+    assert_eq!(
+        run_select(&source("pending()", "pending()", "ready(3)"))?,
+        33
+    );
+    Ok(())
+}
+
+#[test]
+fn test_select_default_branch() -> runestick::Result<()> {
+    // NB: the default branch is taken once all futures have completed.
+    let source = r#"
+    pub async fn main() {
+        let a = ready(1);
+        let b = ready(2);
+        let sum = a.await + b.await;
+
+        select {
+            n = a => n,
+            _ = b => 0,
+            default => sum * 10,
+        }
+    }
+    "#;
+
+    assert_eq!(run_select(source)?, 30);
+    Ok(())
+}
+
+#[test]
+fn test_select_timeout() -> runestick::Result<()> {
+    let source = |a: &str| {
+        format!(
+            r#"
+            pub async fn main() {{
+                let a = {};
+
+                select {{
+                    n = a => n,
+                    default(std::time::Duration::from_millis(100)) => 42,
+                }}
+            }}
+            "#,
+            a
+        )
+    };
+
+    let timeouts = Rc::new(RefCell::new(Vec::new()));
+
+    let timer = |timeouts: Rc<RefCell<Vec<Duration>>>| {
+        move |vm: &mut runestick::Vm| {
+            // NB: the timer expires immediately, but is only consulted once
+            // the futures being selected over are pending.
+            vm.set_timer(move |duration| {
+                timeouts.borrow_mut().push(duration);
+                async {}
+            });
+        }
+    };
+
+    assert_eq!(
+        run_select_with(&source("pending()"), timer(timeouts.clone()))?,
+        42
+    );
+    assert_eq!(
+        run_select_with(&source("ready(1)"), timer(timeouts.clone()))?,
+        1
+    );
+    assert_eq!(&*timeouts.borrow(), &[Duration::from_millis(100); 2]);
+
+    let error = run_select(&source("pending()"))
+        .unwrap_err()
+        .downcast::<runestick::VmError>()
+        .expect("expected virtual machine error");
+    let (error, _) = error.into_unwound();
+    assert!(matches!(error.kind(), runestick::VmErrorKind::MissingTimer));
+    Ok(())
+}
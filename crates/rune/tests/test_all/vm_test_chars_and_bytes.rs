@@ -0,0 +1,91 @@
+use rune::testing::*;
+use runestick::{Context, Module, VmErrorKind, VmIntegerRepr};
+use std::sync::Arc;
+
+fn context() -> runestick::Result<Arc<Context>> {
+    let mut module = Module::new(&["test"]);
+    module.function(&["ascii"], |c: char| c as u8)?;
+    module.function(&["next_byte"], |b: u8| b.wrapping_add(1))?;
+
+    let mut context = Context::with_default_modules()?;
+    context.install(&module)?;
+    Ok(Arc::new(context))
+}
+
+fn vm_error<T>(result: Result<T, RunError>) -> VmErrorKind
+where
+    T: std::fmt::Debug,
+{
+    match result.unwrap_err() {
+        RunError::VmError(error) => match error.into_unwound().0.into_kind() {
+            VmErrorKind::BadArgument { error, arg: 0, .. } => error.into_kind(),
+            kind => panic!("expected bad argument, got {:?}", kind),
+        },
+        error => panic!("expected vm error, got {:?}", error),
+    }
+}
+
+#[test]
+fn test_native_chars_and_bytes() -> runestick::Result<()> {
+    let context = context()?;
+
+    let output: (u8, char, bool) = run(
+        &context,
+        &["main"],
+        (),
+        r#"
+        pub fn main() {
+            let a = test::ascii('a');
+            (a, 'b', a == b'a')
+        }
+        "#,
+    )?;
+
+    assert_eq!(output, (b'a', 'b', true));
+
+    let output: (u8, u8) = run(
+        &context,
+        &["main"],
+        (),
+        r#"pub fn main() { (test::ascii(98), test::next_byte(255)) }"#,
+    )?;
+
+    assert_eq!(output, (b'b', 0));
+    Ok(())
+}
+
+#[test]
+fn test_native_chars_and_bytes_out_of_range() -> runestick::Result<()> {
+    let context = context()?;
+
+    let kind = vm_error(run::<_, _, u8>(
+        &context,
+        &["main"],
+        (),
+        r#"pub fn main() { test::next_byte(256) }"#,
+    ));
+
+    match kind {
+        VmErrorKind::ValueToIntegerCoercionError {
+            from: VmIntegerRepr::I64(256),
+            to: "u8",
+        } => (),
+        kind => panic!("unexpected error {:?}", kind),
+    }
+
+    let kind = vm_error(run::<_, _, u8>(
+        &context,
+        &["main"],
+        (),
+        r#"pub fn main() { test::ascii(0xd800) }"#,
+    ));
+
+    match kind {
+        VmErrorKind::ValueToCharCoercionError {
+            from: VmIntegerRepr::I64(0xd800),
+        } => (),
+        kind => panic!("unexpected error {:?}", kind),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use rune::testing::*;
+use std::sync::Arc;
+
+#[test]
+fn test_external_constant() -> runestick::Result<()> {
+    let mut module = runestick::Module::new(&["config"]);
+    module.constant(&["LIMIT"], 4i64)?;
+    module.constant(&["NAME"], String::from("limits"))?;
+
+    let mut context = rune_modules::default_context()?;
+    context.install(&module)?;
+    let context = Arc::new(context);
+
+    let output: (i64, i64, usize, String) = run(
+        &context,
+        &["main"],
+        (),
+        r#"
+        use config::{LIMIT, NAME};
+
+        const DOUBLE = LIMIT * 2;
+
+        pub fn main() {
+            let values = [];
+
+            for n in 0..config::LIMIT {
+                values.push(n);
+            }
+
+            (DOUBLE, LIMIT + 1, values.len(), NAME)
+        }
+        "#,
+    )?;
+
+    assert_eq!(output, (8, 5, 4, String::from("limits")));
+    Ok(())
+}
+
+#[test]
+fn test_conflicting_external_constant() -> runestick::Result<()> {
+    let mut module = runestick::Module::new(&["config"]);
+    module.constant(&["LIMIT"], 4i64)?;
+    assert!(module.constant(&["LIMIT"], 2i64).is_err());
+    Ok(())
+}
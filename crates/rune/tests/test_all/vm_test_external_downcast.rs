@@ -0,0 +1,44 @@
+use runestick::{Any, Value};
+use std::sync::Arc;
+
+#[derive(Debug, PartialEq, Any)]
+struct Config {
+    name: String,
+    retries: u32,
+}
+
+#[derive(Any)]
+struct Other;
+
+#[test]
+fn test_external_downcast() -> runestick::Result<()> {
+    let context = Arc::new(rune_modules::default_context()?);
+
+    let config = Config {
+        name: String::from("service"),
+        retries: 3,
+    };
+
+    let value: Value = rune::testing::run(
+        &context,
+        &["main"],
+        (config,),
+        r#"
+        pub fn main(config) { config }
+        "#,
+    )?;
+
+    assert!(value.downcast_ref::<Other>()?.is_none());
+
+    value.downcast_mut::<Config>()?.unwrap().retries += 1;
+
+    let config = value.downcast_ref::<Config>()?.unwrap();
+    assert_eq!(
+        *config,
+        Config {
+            name: String::from("service"),
+            retries: 4,
+        }
+    );
+    Ok(())
+}
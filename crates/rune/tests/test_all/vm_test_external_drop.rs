@@ -0,0 +1,135 @@
+use runestick::Any;
+use std::sync::{Arc, Mutex};
+
+static LOG: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// A host value which records in the log when it's dropped.
+#[derive(Any)]
+struct Handle {
+    name: String,
+}
+
+impl Handle {
+    fn new(name: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+        }
+    }
+
+    fn drop(&self) {
+        LOG.lock().unwrap().push(format!("drop {}", self.name));
+    }
+}
+
+/// Serializes tests, since they share the log.
+static TEST: Mutex<()> = Mutex::new(());
+
+fn mark(event: &str) {
+    LOG.lock().unwrap().push(event.to_owned());
+}
+
+/// Run the given source and collect everything that was logged.
+fn run_logged(source: &str) -> runestick::Result<Vec<String>> {
+    let _guard = TEST.lock().unwrap();
+    LOG.lock().unwrap().clear();
+
+    let mut module = runestick::Module::default();
+    module.ty::<Handle>()?;
+    module.function(&["Handle", "new"], Handle::new)?;
+    module.function(&["mark"], mark)?;
+    module.inst_fn(runestick::DROP, Handle::drop)?;
+
+    let mut context = rune_modules::default_context()?;
+    context.install(&module)?;
+    let context = Arc::new(context);
+
+    let () = rune::testing::run(&context, &["main"], (), source)?;
+    Ok(std::mem::take(&mut *LOG.lock().unwrap()))
+}
+
+#[test]
+fn test_external_drop() -> runestick::Result<()> {
+    let log = run_logged(
+        r#"
+        pub fn main() {
+            let a = Handle::new("a");
+
+            {
+                let b = Handle::new("b");
+                let c = b;
+                mark("inner");
+            }
+
+            mark("outer");
+        }
+        "#,
+    )?;
+
+    // NB: `c` only aliases `b`, so the handle is dropped once both have gone
+    // out of scope.
+    assert_eq!(log, ["inner", "drop b", "outer", "drop a"]);
+    Ok(())
+}
+
+#[test]
+fn test_external_drop_break_continue() -> runestick::Result<()> {
+    let log = run_logged(
+        r#"
+        pub fn main() {
+            for n in 0..4 {
+                let h = Handle::new(`${n}`);
+
+                if n == 1 {
+                    continue;
+                }
+
+                if n == 2 {
+                    break;
+                }
+
+                mark(`end ${n}`);
+            }
+
+            mark("done");
+        }
+        "#,
+    )?;
+
+    assert_eq!(log, ["end 0", "drop 0", "drop 1", "drop 2", "done"]);
+    Ok(())
+}
+
+#[test]
+fn test_external_drop_return() -> runestick::Result<()> {
+    let log = run_logged(
+        r#"
+        fn early(n) {
+            let h = Handle::new(`${n}`);
+
+            if n > 0 {
+                return n;
+            }
+
+            mark("late");
+            n
+        }
+
+        fn early_try(n) {
+            let h = Handle::new(`try ${n}`);
+            let value = Err(n)?;
+            mark("unreachable");
+            value
+        }
+
+        pub fn main() {
+            early(1);
+            early(0);
+            early_try(2);
+            mark("done");
+        }
+        "#,
+    )?;
+
+    assert_eq!(log, ["drop 1", "late", "drop 0", "drop try 2", "done"]);
+    Ok(())
+}
@@ -0,0 +1,110 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+
+static READY: AtomicBool = AtomicBool::new(false);
+static WAKER: Mutex<Option<Waker>> = Mutex::new(None);
+
+/// A future which is pending until [READY] is set by the host.
+struct Gate;
+
+impl Future for Gate {
+    type Output = i64;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<i64> {
+        if READY.load(Ordering::SeqCst) {
+            return Poll::Ready(42);
+        }
+
+        *WAKER.lock().unwrap() = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+/// A waker which counts the number of times it has been woken.
+struct CountingWaker(AtomicUsize);
+
+impl Wake for CountingWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn test_await_yields_to_host_executor() -> runestick::Result<()> {
+    let mut module = runestick::Module::default();
+    module.async_function(&["gate"], || Gate)?;
+
+    let mut context = rune_modules::default_context()?;
+    context.install(&module)?;
+    let context = Arc::new(context);
+
+    let (unit, _) =
+        rune::testing::compile_source(&context, r#"pub async fn main() { gate().await + 1 }"#)
+            .expect("source should compile");
+
+    let vm = runestick::Vm::new(context, Arc::new(unit));
+    let mut future = Box::pin(vm.async_call(&["main"], ()));
+
+    let counter = Arc::new(CountingWaker(AtomicUsize::new(0)));
+    let waker = Waker::from(counter.clone());
+    let mut cx = Context::from_waker(&waker);
+
+    // NB: the virtual machine yields to the executor driving it, handing the
+    // executor's waker to the future being awaited.
+    assert!(future.as_mut().poll(&mut cx).is_pending());
+    assert!(future.as_mut().poll(&mut cx).is_pending());
+
+    READY.store(true, Ordering::SeqCst);
+    WAKER
+        .lock()
+        .unwrap()
+        .take()
+        .expect("waker registered")
+        .wake();
+    assert_eq!(counter.0.load(Ordering::SeqCst), 1);
+
+    let output = match future.as_mut().poll(&mut cx) {
+        Poll::Ready(output) => output?,
+        Poll::Pending => panic!("expected virtual machine to complete"),
+    };
+
+    assert_eq!(runestick::FromValue::from_value(output).ok(), Some(43i64));
+    Ok(())
+}
+
+/// A minimal executor which polls the given future until it completes.
+fn block_on<F>(future: F) -> F::Output
+where
+    F: Future,
+{
+    let mut future = Box::pin(future);
+    let waker = Waker::from(Arc::new(CountingWaker(AtomicUsize::new(0))));
+    let mut cx = Context::from_waker(&waker);
+
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+#[test]
+fn test_call_async() -> runestick::Result<()> {
+    let context = Arc::new(rune_modules::default_context()?);
+
+    let (unit, _) = rune::testing::compile_source(&context, r#"pub async fn main() { 1 + 1 }"#)
+        .expect("source should compile");
+
+    let vm = runestick::Vm::new(context, Arc::new(unit));
+
+    // NB: the future doesn't borrow anything, so it's free to be moved into
+    // the executor.
+    let future = vm.call_async(&["main"], ());
+    let output = block_on(future)?;
+
+    assert_eq!(runestick::FromValue::from_value(output).ok(), Some(2i64));
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use runestick::{Any, Context, FromValue, Module, Vm, VmError};
+use std::sync::Arc;
+
+#[derive(Debug, Default, Any)]
+struct Grid {
+    cells: Vec<i64>,
+}
+
+impl Grid {
+    fn new(len: usize) -> Self {
+        Self {
+            cells: vec![0; len],
+        }
+    }
+
+    fn index_get(&self, index: usize) -> Result<i64, VmError> {
+        match self.cells.get(index) {
+            Some(value) => Ok(*value),
+            None => Err(VmError::panic("index out of bounds")),
+        }
+    }
+
+    fn index_set(&mut self, index: usize, value: i64) -> Result<(), VmError> {
+        match self.cells.get_mut(index) {
+            Some(cell) => {
+                *cell = value;
+                Ok(())
+            }
+            None => Err(VmError::panic("index out of bounds")),
+        }
+    }
+}
+
+fn context() -> runestick::Result<Arc<Context>> {
+    let mut module = Module::empty();
+    module.ty::<Grid>()?;
+    module.function(&["Grid", "new"], Grid::new)?;
+    module.inst_fn(runestick::INDEX_GET, Grid::index_get)?;
+    module.inst_fn(runestick::INDEX_SET, Grid::index_set)?;
+
+    let mut context = Context::with_default_modules()?;
+    context.install(&module)?;
+    Ok(Arc::new(context))
+}
+
+#[test]
+fn test_external_index() -> runestick::Result<()> {
+    let context = context()?;
+
+    let (unit, _) = rune::testing::compile_source(
+        &context,
+        r#"
+        pub fn main() {
+            let m = Grid::new(2);
+            m[0] = 40;
+            m[1] = m[0] + 2;
+            m[1]
+        }
+        "#,
+    )
+    .expect("source should compile");
+
+    let vm = Vm::new(context, Arc::new(unit));
+    let output = i64::from_value(vm.call(&["main"], ())?)?;
+    assert_eq!(output, 42);
+    Ok(())
+}
+
+#[test]
+fn test_external_index_out_of_bounds() -> runestick::Result<()> {
+    let context = context()?;
+
+    let (unit, _) =
+        rune::testing::compile_source(&context, r#"pub fn main() { let m = Grid::new(2); m[2] }"#)
+            .expect("source should compile");
+
+    let vm = Vm::new(context, Arc::new(unit));
+    assert!(vm.call(&["main"], ()).is_err());
+    Ok(())
+}
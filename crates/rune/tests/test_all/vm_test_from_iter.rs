@@ -0,0 +1,46 @@
+use rune::testing::*;
+use runestick::{Context, Module, Object, Value};
+use std::sync::Arc;
+
+fn pairs() -> Object {
+    vec![("a", 1i64), ("b", 2i64)]
+        .into_iter()
+        .map(|(key, value)| (key.to_owned(), Value::from(value)))
+        .collect()
+}
+
+fn numbers() -> runestick::Vec {
+    (1..=3i64).map(Value::from).collect()
+}
+
+#[test]
+fn test_native_collections_from_iter() -> runestick::Result<()> {
+    let mut module = Module::new(&["test"]);
+    module.function(&["pairs"], pairs)?;
+    module.function(&["numbers"], numbers)?;
+
+    let mut context = Context::with_default_modules()?;
+    context.install(&module)?;
+    let context = Arc::new(context);
+
+    let output: (i64, i64, i64) = run(
+        &context,
+        &["main"],
+        (),
+        r#"
+        pub fn main() {
+            let object = test::pairs();
+            let sum = 0;
+
+            for n in test::numbers() {
+                sum += n;
+            }
+
+            (object.a, object["b"], sum)
+        }
+        "#,
+    )?;
+
+    assert_eq!(output, (1, 2, 6));
+    Ok(())
+}
@@ -37,3 +37,74 @@ fn test_instance_kinds() {
         (4, 5, 6, 7),
     };
 }
+
+#[test]
+fn test_instance_mut_receiver() {
+    assert_eq! {
+        rune! { (i64, Vec<i64>) =>
+            pub fn main() {
+                let v = [];
+                v.push(1);
+                v.push(2);
+
+                let object = #{ items: [] };
+                object.items.push(3);
+
+                (object.items.len(), v)
+            }
+        },
+        (1, vec![1, 2]),
+    };
+}
+
+#[test]
+fn test_instance_mut_receiver_external() {
+    use runestick::{Any, Context, FromValue as _, Module};
+    use std::sync::Arc;
+
+    #[derive(Debug, Default, Any)]
+    struct Counter {
+        count: i64,
+    }
+
+    impl Counter {
+        fn bump(&mut self, n: i64) {
+            self.count += n;
+        }
+
+        fn count(&self) -> i64 {
+            self.count
+        }
+    }
+
+    let mut module = Module::new(&["counter"]);
+    module.ty::<Counter>().unwrap();
+    module
+        .function(&["Counter", "new"], Counter::default)
+        .unwrap();
+    module.inst_fn("bump", Counter::bump).unwrap();
+    module.inst_fn("count", Counter::count).unwrap();
+
+    let mut context = Context::with_default_modules().unwrap();
+    context.install(&module).unwrap();
+    let context = Arc::new(context);
+
+    let (unit, _) = rune::testing::compile_source(
+        &context,
+        r#"
+        use counter::Counter;
+
+        pub fn main() {
+            let counter = Counter::new();
+            counter.bump(2);
+            counter.bump(3);
+            counter.count()
+        }
+        "#,
+    )
+    .unwrap();
+
+    let vm = runestick::Vm::new(context, Arc::new(unit));
+    let output = vm.execute(&["main"], ()).unwrap().complete().unwrap();
+    assert_eq!(i64::from_value(output).unwrap(), 5);
+}
@@ -32,3 +32,63 @@ fn test_unwrap() {
         Err(3),
     };
 }
+
+#[test]
+fn test_try_field_access() {
+    // NB: `?.` is the try operator followed by a field access, like in Rust.
+    // Optional reads of object keys are done through `get` instead.
+    assert_eq! {
+        rune! { (Option<i64>, Option<i64>) =>
+            fn lookup(obj) {
+                Some(obj.get("x")?.y)
+            }
+
+            pub fn main() {
+                (lookup(#{ x: #{ y: 1 } }), lookup(#{ z: #{ y: 2 } }))
+            }
+        },
+        (Some(1), None),
+    };
+}
+
+#[test]
+fn test_try_early_return() {
+    assert_eq! {
+        rune! { (Result<i64, i64>, Result<i64, i64>) =>
+            fn parse(n) {
+                if n < 0 { Err(n) } else { Ok(n * 10) }
+            }
+
+            fn sum(a, b) {
+                let first = parse(a)?;
+                let second = parse(b)?;
+                let total = first + second;
+                Ok(total)
+            }
+
+            pub fn main() {
+                (sum(1, 2), sum(1, -2))
+            }
+        },
+        (Ok(30), Err(-2)),
+    };
+
+    assert_eq! {
+        rune! { (Option<i64>, Option<i64>) =>
+            fn positive(n) {
+                if n > 0 { Some(n) } else { None }
+            }
+
+            fn plus_len(n) {
+                let scratch = [1, 2, 3];
+                let value = positive(n)?;
+                Some(value + scratch.len())
+            }
+
+            pub fn main() {
+                (plus_len(1), plus_len(0))
+            }
+        },
+        (Some(4), None),
+    };
+}
@@ -1,3 +1,5 @@
+use rune::testing::*;
+
 #[test]
 fn test_mutate_tuples() {
     assert_eq! {
@@ -12,3 +14,97 @@ fn test_mutate_tuples() {
         "Now You Don't !",
     };
 }
+
+#[test]
+fn test_expected_tuple_return() {
+    assert_vm_error!(
+        (i64, i64) => r#"pub fn main() { 42 }"#,
+        ExpectedTuple { actual, expected } => {
+            assert_eq!(actual.to_string(), "integer");
+            assert_eq!(expected, 2);
+        }
+    );
+
+    assert_vm_error!(
+        (i64, i64) => r#"pub fn main() { (1, 2, 3) }"#,
+        ExpectedTupleLength { actual, expected } => {
+            assert_eq!(actual, 3);
+            assert_eq!(expected, 2);
+        }
+    );
+}
+
+#[test]
+fn test_tuple_iter() {
+    assert_eq! {
+        rune! { i64 =>
+            pub fn main() {
+                let sum = 0;
+
+                for n in (1, 2, 3, 4) {
+                    sum += n;
+                }
+
+                sum
+            }
+        },
+        10,
+    };
+
+    assert_eq! {
+        rune! { (i64, Option<i64>, Option<i64>, Option<i64>) =>
+            pub fn main() {
+                let t = (1, 2);
+                let it = t.iter();
+                (t.len(), it.next(), it.next(), it.next())
+            }
+        },
+        (2, Some(1), Some(2), None),
+    };
+}
+
+#[test]
+fn test_tuple_get() {
+    assert_eq! {
+        rune! { (Option<i64>, Option<i64>, Option<i64>, Option<i64>) =>
+            pub fn main() {
+                let t = (1, 2);
+                (t.get(0), t.get(1), t.get(5), t.get(-1))
+            }
+        },
+        (Some(1), Some(2), None, None),
+    };
+}
+
+#[test]
+fn test_tuple_concat() {
+    assert_eq! {
+        rune! { ((i64, i64, i64), (i64, i64)) =>
+            pub fn main() {
+                let a = (1, 2);
+                let b = (3,);
+                (a + b, a)
+            }
+        },
+        ((1, 2, 3), (1, 2)),
+    };
+
+    assert_eq! {
+        rune! { (i64, i64, i64) => pub fn main() { (1,) + (2,) + (3,) } },
+        (1, 2, 3),
+    };
+}
+
+#[test]
+fn test_tuple_first_last() {
+    assert_eq! {
+        rune! { (Option<i64>, Option<i64>, Option<i64>, Option<i64>) =>
+            pub fn main() {
+                let t = (1, 2, 3);
+                let u = (4,);
+                (t.first(), t.last(), u.first(), u.last())
+            }
+        },
+        (Some(1), Some(3), Some(4), Some(4)),
+    };
+}
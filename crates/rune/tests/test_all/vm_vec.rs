@@ -0,0 +1,44 @@
+use rune::testing::*;
+use runestick::VmErrorKind;
+
+#[test]
+fn test_vec_sort() {
+    assert_eq! {
+        rune!(Vec<i64> => pub fn main() { let v = [3, 1, 2]; v.sort(); v }),
+        vec![1, 2, 3],
+    };
+
+    assert_eq! {
+        rune!(Vec<String> => pub fn main() { let v = ["b", "c", "a"]; v.sort(); v }),
+        vec![String::from("a"), String::from("b"), String::from("c")],
+    };
+}
+
+#[test]
+fn test_vec_sort_by() {
+    assert_eq! {
+        rune!(Vec<i64> => pub fn main() {
+            let v = [3, 1, 2];
+            v.sort_by(|a, b| if a < b { 1 } else if a > b { -1 } else { 0 });
+            v
+        }),
+        vec![3, 2, 1],
+    };
+}
+
+#[test]
+fn test_vec_sort_mixed() {
+    assert_vm_error!(
+        r#"pub fn main() { let v = [1, "a"]; v.sort(); }"#,
+        BadReturn { error, .. } => {
+            match error.kind() {
+                VmErrorKind::UnsupportedBinaryOperation { op, lhs, rhs } => {
+                    assert_eq!(*op, "partial_cmp");
+                    assert_eq!(lhs.to_string(), "String");
+                    assert_eq!(rhs.to_string(), "integer");
+                }
+                kind => panic!("unexpected error kind: {:?}", kind),
+            }
+        }
+    );
+}
@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+#[global_allocator]
+static ALLOCATOR: checkers::Allocator = checkers::Allocator::system();
+
+/// Count the allocations performed when calling the given function.
+fn allocations(vm: &runestick::Vm, name: &str) -> usize {
+    let vm = runestick::Vm::new_with_stack(
+        vm.context().clone(),
+        vm.unit().clone(),
+        runestick::Stack::with_capacity(16),
+    );
+
+    let mut output = None;
+
+    let snapshot = checkers::with(|| {
+        output = Some(vm.call(&[name], ()).unwrap());
+    });
+
+    drop(output);
+    snapshot.events.allocs()
+}
+
+#[test]
+fn test_empty_vec_allocations() {
+    let context = Arc::new(rune_modules::default_context().unwrap());
+    let (unit, _) =
+        rune::testing::compile_source(&context, r#"pub fn empty() { [] } pub fn one() { [1] }"#)
+            .unwrap();
+
+    let vm = runestick::Vm::new(context, Arc::new(unit));
+
+    let empty = allocations(&vm, "empty");
+    let one = allocations(&vm, "one");
+
+    // NB: an empty vector only allocates its shared container, and never a
+    // buffer for its elements.
+    assert_eq!(empty + 1, one);
+}
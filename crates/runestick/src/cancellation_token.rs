@@ -0,0 +1,33 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// A token which can be used to cancel the execution of a virtual machine,
+/// possibly from another thread.
+///
+/// Once cancelled, the virtual machine stops before executing its next
+/// instruction with [VmErrorKind::Cancelled][crate::VmErrorKind::Cancelled].
+///
+/// See [Vm::cancellation_token][crate::Vm::cancellation_token].
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Construct a new cancellation token.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel the execution of every virtual machine associated with this
+    /// token.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Release);
+    }
+
+    /// Test if the token has been cancelled.
+    #[inline]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Acquire)
+    }
+}
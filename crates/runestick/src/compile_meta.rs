@@ -20,6 +20,8 @@ pub struct CompileMeta {
     pub kind: CompileMetaKind,
     /// The source of the meta.
     pub source: Option<CompileSource>,
+    /// The doc strings of the item, one for each line of doc comments.
+    pub docs: Vec<String>,
 }
 
 /// Information on a compile sourc.
@@ -3,8 +3,8 @@ use crate::module::{
     ModuleAssociatedFn, ModuleFn, ModuleInternalEnum, ModuleMacro, ModuleType, ModuleUnitType,
 };
 use crate::{
-    CompileMeta, CompileMetaKind, CompileMetaStruct, CompileMetaTuple, ComponentRef, Hash,
-    IntoComponent, Item, Module, Names, Stack, StaticType, Type, TypeCheck, TypeInfo, TypeOf,
+    CompileMeta, CompileMetaKind, CompileMetaStruct, CompileMetaTuple, ComponentRef, ConstValue,
+    Hash, IntoComponent, Item, Module, Names, Stack, StaticType, Type, TypeCheck, TypeInfo, TypeOf,
     VmError,
 };
 use std::any;
@@ -33,10 +33,16 @@ pub enum ContextError {
         existing: Box<CompileMeta>,
     },
     /// Error raised when attempting to register a conflicting function.
-    #[error("function `{signature}` ({hash}) already exists")]
+    #[error("function `{current}` from module `{current_module}` conflicts with existing function `{existing}` from module `{existing_module}` ({hash})")]
     ConflictingFunction {
-        /// The signature of the conflicting function.
-        signature: ContextSignature,
+        /// The signature of the function we tried to register.
+        current: ContextSignature,
+        /// The module of the function we tried to register.
+        current_module: Item,
+        /// The signature of the existing function.
+        existing: ContextSignature,
+        /// The module of the existing function.
+        existing_module: Item,
         /// The hash of the conflicting function.
         hash: Hash,
     },
@@ -46,6 +52,18 @@ pub enum ContextError {
         /// The name of the conflicting function.
         name: Item,
     },
+    /// Error raised when attempting to register a conflicting constant.
+    #[error("constant with name `{name}` already exists")]
+    ConflictingConstantName {
+        /// The name of the conflicting constant.
+        name: Item,
+    },
+    /// Error raised when a value couldn't be converted into a constant.
+    #[error("value could not be converted into a constant: {error}")]
+    ValueError {
+        /// The source error.
+        error: VmError,
+    },
     /// Error raised when attempting to register a conflicting instance function.
     #[error("instance function `{name}` for type `{type_info}` already exists")]
     ConflictingInstanceFunction {
@@ -132,6 +150,8 @@ pub enum ContextSignature {
         item: Item,
         /// Arguments.
         args: Option<usize>,
+        /// If the function is async.
+        is_async: bool,
     },
     Instance {
         /// Path to the instance function.
@@ -140,6 +160,8 @@ pub enum ContextSignature {
         name: String,
         /// Arguments.
         args: Option<usize>,
+        /// If the instance function is async.
+        is_async: bool,
         /// Information on the self type.
         self_type_info: TypeInfo,
     },
@@ -148,7 +170,15 @@ pub enum ContextSignature {
 impl fmt::Display for ContextSignature {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::Function { item, args } => {
+            Self::Function {
+                item,
+                args,
+                is_async,
+            } => {
+                if *is_async {
+                    write!(fmt, "async ")?;
+                }
+
                 write!(fmt, "{}(", item)?;
 
                 if let Some(args) = args {
@@ -173,7 +203,12 @@ impl fmt::Display for ContextSignature {
                 name,
                 self_type_info,
                 args,
+                is_async,
             } => {
+                if *is_async {
+                    write!(fmt, "async ")?;
+                }
+
                 write!(fmt, "{}::{}(self: {}", item, name, self_type_info)?;
 
                 if let Some(args) = args {
@@ -198,6 +233,10 @@ impl fmt::Display for ContextSignature {
 /// * Declared functions.
 /// * Declared instance functions.
 /// * Type definitions.
+///
+/// A context has no interior mutability, so once it has been constructed it is
+/// `Send + Sync`. A single `Arc<Context>` can therefore be shared by any number
+/// of virtual machines running on different threads.
 #[derive(Default)]
 pub struct Context {
     /// Whether or not to include the prelude when constructing a new unit.
@@ -210,6 +249,8 @@ pub struct Context {
     macros: HashMap<Hash, Arc<Macro>>,
     /// Information on functions.
     functions_info: HashMap<Hash, ContextSignature>,
+    /// The path of the module each function was installed from.
+    functions_module: HashMap<Hash, Item>,
     /// Registered types.
     types: HashMap<Hash, ContextTypeInfo>,
     /// Reverse lookup for types.
@@ -220,6 +261,8 @@ pub struct Context {
     internal_enums: HashSet<&'static StaticType>,
     /// All available names in the context.
     names: Names<()>,
+    /// If any type implements the [DROP][crate::DROP] protocol.
+    has_drop_fns: bool,
 }
 
 impl Context {
@@ -248,18 +291,22 @@ impl Context {
         this.install(&crate::modules::core::module()?)?;
         this.install(&crate::modules::generator::module()?)?;
         this.install(&crate::modules::bytes::module()?)?;
+        this.install(&crate::modules::char::module()?)?;
         this.install(&crate::modules::string::module()?)?;
         this.install(&crate::modules::int::module()?)?;
         this.install(&crate::modules::float::module()?)?;
         this.install(&crate::modules::iter::module()?)?;
         this.install(&crate::modules::vec::module()?)?;
+        this.install(&crate::modules::tuple::module()?)?;
         this.install(&crate::modules::object::module()?)?;
         this.install(&crate::modules::result::module()?)?;
         this.install(&crate::modules::option::module()?)?;
         this.install(&crate::modules::future::module()?)?;
         this.install(&crate::modules::stream::module()?)?;
+        this.install(&crate::modules::time::module()?)?;
         this.install(&crate::modules::io::module(stdio)?)?;
         this.install(&crate::modules::fmt::module()?)?;
+        this.install(&crate::modules::test::module()?)?;
         this.has_default_modules = true;
         Ok(this)
     }
@@ -299,6 +346,14 @@ impl Context {
         self.names.contains_prefix(item)
     }
 
+    /// Test if any type in the context implements the [DROP][crate::DROP]
+    /// protocol.
+    ///
+    /// If none do, there's no need to drop locals as they go out of scope.
+    pub fn has_drop_fns(&self) -> bool {
+        self.has_drop_fns
+    }
+
     /// Lookup the given native function handler in the context.
     pub fn lookup(&self, hash: Hash) -> Option<&Arc<Handler>> {
         self.functions.get(&hash)
@@ -314,6 +369,16 @@ impl Context {
         self.meta.get(name).cloned()
     }
 
+    /// Iterate over all available constants.
+    pub fn iter_constants(&self) -> impl Iterator<Item = (&Item, &ConstValue)> {
+        self.meta
+            .iter()
+            .filter_map(|(item, meta)| match &meta.kind {
+                CompileMetaKind::Const { const_value } => Some((item, const_value)),
+                _ => None,
+            })
+    }
+
     /// Iterate over all available functions
     pub fn iter_functions(&self) -> impl Iterator<Item = (Hash, &ContextSignature)> {
         let mut it = self.functions_info.iter();
@@ -335,6 +400,12 @@ impl Context {
     }
 
     /// Install the specified module.
+    ///
+    /// Installing a function at an item which is already occupied, like one
+    /// provided by the default modules, results in a
+    /// [ContextError::ConflictingFunction] error unless the module being
+    /// installed has been marked with [Module::allow_overrides]. In that case
+    /// the function of the most recently installed module takes precedence.
     pub fn install(&mut self, module: &Module) -> Result<(), ContextError> {
         for (type_of, ty) in &module.types {
             self.install_type(&module, *type_of, ty)?;
@@ -348,6 +419,10 @@ impl Context {
             self.install_macro(&module, name, m)?;
         }
 
+        for (name, const_value) in &module.constants {
+            self.install_constant(&module, name, const_value)?;
+        }
+
         if let Some(unit_type) = &module.unit_type {
             self.install_unit_type(&module, unit_type)?;
         }
@@ -357,12 +432,53 @@ impl Context {
         }
 
         for (key, inst) in &module.associated_functions {
-            self.install_associated_function(key.type_of, key.hash, inst, key.kind.into_hash_fn())?;
+            if key.hash == crate::DROP.hash {
+                self.has_drop_fns = true;
+            }
+
+            self.install_associated_function(
+                module,
+                key.type_of,
+                key.hash,
+                inst,
+                key.kind.into_hash_fn(),
+            )?;
         }
 
         Ok(())
     }
 
+    /// Install information on a function, checking that it doesn't conflict
+    /// with an existing function unless the module permits overrides.
+    fn install_function_info(
+        &mut self,
+        module: &Module,
+        hash: Hash,
+        signature: ContextSignature,
+    ) -> Result<(), ContextError> {
+        if !module.overrides {
+            if let Some(existing) = self.functions_info.get(&hash) {
+                let existing_module = self
+                    .functions_module
+                    .get(&hash)
+                    .cloned()
+                    .unwrap_or_default();
+
+                return Err(ContextError::ConflictingFunction {
+                    current: signature,
+                    current_module: module.path.clone(),
+                    existing: existing.clone(),
+                    existing_module,
+                    hash,
+                });
+            }
+        }
+
+        self.functions_info.insert(hash, signature);
+        self.functions_module.insert(hash, module.path.clone());
+        Ok(())
+    }
+
     /// Install the given meta.
     fn install_meta(&mut self, meta: CompileMeta) -> Result<(), ContextError> {
         if let Some(existing) = self.meta.insert(meta.item.clone(), meta.clone()) {
@@ -402,6 +518,7 @@ impl Context {
                 object: CompileMetaStruct { fields: None },
             },
             source: None,
+            docs: Vec::new(),
         })?;
 
         Ok(())
@@ -444,15 +561,10 @@ impl Context {
         let signature = ContextSignature::Function {
             item: item.clone(),
             args: f.args,
+            is_async: f.is_async,
         };
 
-        if let Some(old) = self.functions_info.insert(hash, signature) {
-            return Err(ContextError::ConflictingFunction {
-                signature: old,
-                hash,
-            });
-        }
-
+        self.install_function_info(module, hash, signature)?;
         self.functions.insert(hash, f.handler.clone());
 
         self.meta.insert(
@@ -463,6 +575,7 @@ impl Context {
                     type_of: Type::from(hash),
                 },
                 source: None,
+                docs: Vec::new(),
             },
         );
 
@@ -486,8 +599,29 @@ impl Context {
         Ok(())
     }
 
+    /// Install a constant and check for duplicates.
+    fn install_constant(
+        &mut self,
+        module: &Module,
+        item: &Item,
+        const_value: &ConstValue,
+    ) -> Result<(), ContextError> {
+        let item = module.path.join(item);
+        self.names.insert(&item, ());
+
+        self.install_meta(CompileMeta {
+            item,
+            kind: CompileMetaKind::Const {
+                const_value: const_value.clone(),
+            },
+            source: None,
+            docs: Vec::new(),
+        })
+    }
+
     fn install_associated_function(
         &mut self,
+        module: &Module,
         type_of: Type,
         hash: Hash,
         assoc: &ModuleAssociatedFn,
@@ -512,16 +646,11 @@ impl Context {
             item: info.item.clone(),
             name: assoc.name.clone(),
             args: assoc.args,
+            is_async: assoc.is_async,
             self_type_info: info.type_info.clone(),
         };
 
-        if let Some(old) = self.functions_info.insert(hash, signature) {
-            return Err(ContextError::ConflictingFunction {
-                signature: old,
-                hash,
-            });
-        }
-
+        self.install_function_info(module, hash, signature)?;
         self.functions.insert(hash, assoc.handler.clone());
         Ok(())
     }
@@ -539,7 +668,7 @@ impl Context {
         let item = module.path.extended(&*unit_type.name);
         let hash = Hash::type_hash(&item);
         self.unit_type = Some(Hash::type_hash(&item));
-        self.add_internal_tuple(None, item.clone(), 0, || ())?;
+        self.add_internal_tuple(module, None, item.clone(), 0, || ())?;
 
         self.install_type_info(
             hash,
@@ -575,6 +704,7 @@ impl Context {
                 type_of: Type::from(internal_enum.static_type),
             },
             source: None,
+            docs: Vec::new(),
         })?;
 
         self.install_type_info(
@@ -612,20 +742,16 @@ impl Context {
                     },
                 },
                 source: None,
+                docs: Vec::new(),
             })?;
 
             let signature = ContextSignature::Function {
                 item,
                 args: Some(variant.args),
+                is_async: false,
             };
 
-            if let Some(old) = self.functions_info.insert(hash, signature) {
-                return Err(ContextError::ConflictingFunction {
-                    signature: old,
-                    hash,
-                });
-            }
-
+            self.install_function_info(module, hash, signature)?;
             self.functions.insert(hash, variant.constructor.clone());
         }
 
@@ -635,6 +761,7 @@ impl Context {
     /// Add a piece of internal tuple meta.
     fn add_internal_tuple<C, Args>(
         &mut self,
+        module: &Module,
         enum_item: Option<Item>,
         item: Item,
         args: usize,
@@ -658,11 +785,13 @@ impl Context {
                     tuple,
                 },
                 source: None,
+                docs: Vec::new(),
             },
             None => CompileMeta {
                 item: item.clone(),
                 kind: CompileMetaKind::TupleStruct { type_of, tuple },
                 source: None,
+                docs: Vec::new(),
             },
         };
 
@@ -674,15 +803,10 @@ impl Context {
         let signature = ContextSignature::Function {
             item,
             args: Some(args),
+            is_async: false,
         };
 
-        if let Some(old) = self.functions_info.insert(hash, signature) {
-            return Err(ContextError::ConflictingFunction {
-                signature: old,
-                hash,
-            });
-        }
-
+        self.install_function_info(module, hash, signature)?;
         self.functions.insert(hash, constructor);
         Ok(())
     }
@@ -696,7 +820,8 @@ impl fmt::Debug for Context {
 
 #[cfg(test)]
 mod tests {
-    use super::Context;
+    use super::{Context, ContextSignature};
+    use crate::Item;
 
     fn assert_send_sync<T>()
     where
@@ -708,4 +833,37 @@ mod tests {
     fn assert_thread_safe_context() {
         assert_send_sync::<Context>();
     }
+
+    #[test]
+    fn test_iter_functions_and_types() {
+        let context = Context::with_default_modules().unwrap();
+
+        let dbg = Item::of(&["std", "io", "dbg"]);
+
+        let found = context.iter_functions().any(|(_, f)| match f {
+            ContextSignature::Function {
+                item,
+                args,
+                is_async,
+            } => *item == dbg && args.is_none() && !*is_async,
+            _ => false,
+        });
+
+        assert!(found, "expected `std::io::dbg` to be listed");
+
+        let found = context.iter_functions().any(|(_, f)| match f {
+            ContextSignature::Instance {
+                name,
+                args,
+                is_async,
+                ..
+            } => name == "resume" && *args == Some(2) && *is_async,
+            _ => false,
+        });
+
+        assert!(found, "expected async `Stream::resume` to be listed");
+
+        let tuple = Item::of(&["std", "tuple", "Tuple"]);
+        assert!(context.iter_types().any(|(_, ty)| ty.item == tuple));
+    }
 }
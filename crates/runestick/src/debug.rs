@@ -28,6 +28,18 @@ impl DebugInfo {
         let signature = self.functions.get(&hash)?;
         Some((hash, signature))
     }
+
+    /// Get the function which contains the given instruction pointer.
+    pub fn function_containing(&self, ip: usize) -> Option<(Hash, &DebugSignature)> {
+        let (_, hash) = self
+            .functions_rev
+            .iter()
+            .filter(|(offset, _)| **offset <= ip)
+            .max_by_key(|(offset, _)| **offset)?;
+
+        let signature = self.functions.get(hash)?;
+        Some((*hash, signature))
+    }
 }
 
 /// Debug information for every instruction.
@@ -61,14 +73,17 @@ pub struct DebugSignature {
     pub path: Item,
     /// The number of arguments expected in the function.
     pub args: DebugArgs,
+    /// The doc strings of the function, one for each line of doc comments.
+    pub docs: Vec<String>,
 }
 
 impl DebugSignature {
     /// Construct a new function signature.
-    pub fn new(path: Item, args: Vec<String>) -> Self {
+    pub fn new(path: Item, args: Vec<String>, docs: Vec<String>) -> Self {
         Self {
             path,
             args: DebugArgs::Named(args),
+            docs,
         }
     }
 }
@@ -113,6 +113,19 @@ impl FormatSpec {
         Ok(())
     }
 
+    /// Format the given string, truncating it to the precision if one is
+    /// specified.
+    ///
+    /// The precision counts characters, so a multi-byte character is never
+    /// split.
+    fn format_str(&self, buf: &mut String, s: &str) {
+        if let Some(precision) = self.precision {
+            buf.extend(s.chars().take(precision.get()));
+        } else {
+            buf.push_str(s);
+        }
+    }
+
     /// Format fill.
     fn format_fill(
         &self,
@@ -173,11 +186,11 @@ impl FormatSpec {
                 self.format_fill(out, buf, self.align, self.fill, None);
             }
             Value::String(s) => {
-                buf.push_str(&*s.borrow_ref()?);
+                self.format_str(buf, &*s.borrow_ref()?);
                 self.format_fill(out, buf, self.align, self.fill, None);
             }
             Value::StaticString(s) => {
-                buf.push_str(s.as_ref());
+                self.format_str(buf, s.as_ref());
                 self.format_fill(out, buf, self.align, self.fill, None);
             }
             Value::Integer(n) => {
@@ -289,6 +302,26 @@ impl FormatSpec {
         Ok(())
     }
 
+    fn format_octal(
+        &self,
+        value: &Value,
+        out: &mut String,
+        buf: &mut String,
+    ) -> Result<(), VmErrorKind> {
+        match value {
+            Value::Integer(n) => {
+                let (n, align, fill, sign) = self.int_traits(*n);
+                write!(buf, "{:o}", n).map_err(|_| VmErrorKind::FormatError)?;
+                self.format_fill(out, buf, align, fill, sign);
+            }
+            _ => {
+                return Err(VmErrorKind::FormatError);
+            }
+        }
+
+        Ok(())
+    }
+
     fn format_pointer(
         &self,
         value: &Value,
@@ -333,6 +366,9 @@ impl FormatSpec {
             Type::Binary => {
                 self.format_binary(value, out, buf)?;
             }
+            Type::Octal => {
+                self.format_octal(value, out, buf)?;
+            }
             Type::Pointer => {
                 self.format_pointer(value, out, buf)?;
             }
@@ -366,6 +402,8 @@ pub enum Type {
     LowerHex,
     /// Binary formatting type.
     Binary,
+    /// Octal formatting type.
+    Octal,
     /// Pointer formatting type.
     Pointer,
 }
@@ -380,6 +418,7 @@ impl std::str::FromStr for Type {
             "upper_hex" => Ok(Self::UpperHex),
             "lower_hex" => Ok(Self::LowerHex),
             "binary" => Ok(Self::Binary),
+            "octal" => Ok(Self::Octal),
             "pointer" => Ok(Self::Pointer),
             _ => Err(TypeFromStrError(())),
         }
@@ -410,6 +449,9 @@ impl fmt::Display for Type {
             Self::Binary => {
                 write!(f, "binary")?;
             }
+            Self::Octal => {
+                write!(f, "octal")?;
+            }
             Self::Pointer => {
                 write!(f, "pointer")?;
             }
@@ -1,4 +1,7 @@
-use crate::{Any, AnyObj, Mut, RawMut, RawRef, Ref, Shared, StaticString, Value, VmError};
+use crate::{
+    Any, AnyObj, Mut, RawMut, RawRef, Ref, Shared, StaticString, Value, VmError, VmErrorKind,
+    VmIntegerRepr,
+};
 use std::sync::Arc;
 
 /// Trait for converting from a value.
@@ -322,7 +325,21 @@ impl FromValue for () {
 
 impl FromValue for u8 {
     fn from_value(value: Value) -> Result<Self, VmError> {
-        Ok(value.into_byte()?)
+        use std::convert::TryInto as _;
+
+        // NB: integers are accepted as bytes as long as they are in range.
+        let integer = match value {
+            Value::Integer(integer) => integer,
+            value => return Ok(value.into_byte()?),
+        };
+
+        match integer.try_into() {
+            Ok(byte) => Ok(byte),
+            Err(..) => Err(VmError::from(VmErrorKind::ValueToIntegerCoercionError {
+                from: VmIntegerRepr::I64(integer),
+                to: std::any::type_name::<Self>(),
+            })),
+        }
     }
 }
 
@@ -334,7 +351,21 @@ impl FromValue for bool {
 
 impl FromValue for char {
     fn from_value(value: Value) -> Result<Self, VmError> {
-        Ok(value.into_char()?)
+        use std::convert::TryFrom as _;
+
+        // NB: integers are accepted as characters as long as they are valid
+        // code points.
+        let integer = match value {
+            Value::Integer(integer) => integer,
+            value => return Ok(value.into_char()?),
+        };
+
+        match u32::try_from(integer).ok().and_then(std::char::from_u32) {
+            Some(c) => Ok(c),
+            None => Err(VmError::from(VmErrorKind::ValueToCharCoercionError {
+                from: VmIntegerRepr::I64(integer),
+            })),
+        }
     }
 }
 
@@ -401,7 +432,9 @@ macro_rules! impl_map {
                 let mut output = <$ty>::with_capacity(object.len());
 
                 for (key, value) in object {
-                    output.insert(key, T::from_value(value)?);
+                    let value = T::from_value(value)
+                        .map_err(|e| e.with_path($crate::ValuePathSegment::Key(key.clone())))?;
+                    output.insert(key, value);
                 }
 
                 Ok(output)
@@ -3,6 +3,7 @@ use crate::{
     Value, Vm, VmError, VmErrorKind, VmExecution,
 };
 use std::fmt;
+use std::marker;
 use std::mem;
 
 /// A generator with a stored virtual machine.
@@ -50,6 +51,61 @@ impl Generator {
     }
 }
 
+/// An iterator over the values yielded by a [Generator], converting each of
+/// them using [FromValue].
+///
+/// Values are produced on demand by resuming the generator. If the generator
+/// errors or a value fails to convert, the error is produced as an item and
+/// the iterator is exhausted.
+pub struct GeneratorIter<T> {
+    generator: Option<Generator>,
+    _marker: marker::PhantomData<T>,
+}
+
+impl<T> GeneratorIter<T> {
+    /// Construct an iterator over the values yielded by the given generator.
+    pub fn new(generator: Generator) -> Self {
+        Self {
+            generator: Some(generator),
+            _marker: marker::PhantomData,
+        }
+    }
+}
+
+impl<T> Iterator for GeneratorIter<T>
+where
+    T: FromValue,
+{
+    type Item = Result<T, VmError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let generator = self.generator.as_mut()?;
+
+        let result = match generator.next() {
+            Ok(Some(value)) => T::from_value(value),
+            Ok(None) => {
+                self.generator = None;
+                return None;
+            }
+            Err(error) => Err(error),
+        };
+
+        if result.is_err() {
+            self.generator = None;
+        }
+
+        Some(result)
+    }
+}
+
+impl<T> fmt::Debug for GeneratorIter<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GeneratorIter")
+            .field("generator", &self.generator)
+            .finish()
+    }
+}
+
 impl fmt::Debug for Generator {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Generator")
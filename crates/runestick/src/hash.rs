@@ -4,7 +4,6 @@ use std::any;
 use std::fmt;
 use std::hash;
 use std::hash::{BuildHasher as _, BuildHasherDefault, Hash as _, Hasher as _};
-use std::mem;
 use twox_hash::XxHash64;
 
 const SEP: usize = 0x7f;
@@ -14,6 +13,12 @@ const GETTER: usize = 3;
 const OBJECT_KEYS: usize = 4;
 
 /// The hash of a primitive thing.
+///
+/// Hashes are calculated using `XxHash64` with the default seed, over a kind
+/// marker followed by each component of the thing being hashed. Since hashes
+/// are used to identify items in a compiled [Unit][crate::Unit], the way they
+/// are calculated is part of its format and must not change between versions
+/// without being treated as a breaking change.
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[repr(transparent)]
 pub struct Hash(u64);
@@ -49,9 +54,9 @@ impl Hash {
 
     /// Construct a hash from a type id.
     pub fn from_type_id(type_id: any::TypeId) -> Self {
-        // Safety: a type id is exactly a 64-bit unsigned integer.
-        // And has an identical bit pattern to `Hash`.
-        unsafe { mem::transmute(type_id) }
+        // NB: the size of a type id is not guaranteed, so we hash it down to
+        // 64 bits instead of reinterpreting its bit pattern.
+        Self::of(type_id)
     }
 
     /// Construct a hash to an instance function, where the instance is a
@@ -166,3 +171,29 @@ where
         Item::of(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Hash;
+    use crate::Type;
+
+    /// Hashes identify items in compiled units, so changing how they are
+    /// calculated invalidates any unit which has been serialized. If this test
+    /// fails, the change to hashing must be treated as a breaking change.
+    #[test]
+    fn test_stable_hashes() {
+        let string = Hash::type_hash(&["std", "string", "String"]);
+
+        assert_eq!(string, Hash::new(0x3c95652824957f40));
+        assert_eq!(Hash::type_hash(&["main"]), Hash::new(0xa76ee18c7fed2b52));
+        assert_eq!(Hash::instance_fn_name("len"), Hash::new(0x781ce796b98306a2));
+        assert_eq!(
+            Hash::instance_function(Type::from(string), "len"),
+            Hash::new(0x8552f03b4a087697)
+        );
+        assert_eq!(
+            Hash::object_keys(&["a", "b"]),
+            Hash::new(0xc6c495b88a786950)
+        );
+    }
+}
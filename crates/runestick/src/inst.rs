@@ -1,4 +1,4 @@
-use crate::{FormatSpec, Hash, Value};
+use crate::{FormatSpec, Hash, RangeLimits, Value};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -278,15 +278,24 @@ pub enum Inst {
     /// This operation will block the VM until at least one of the underlying
     /// futures complete.
     ///
+    /// If `timeout` is set, the top of the stack is a duration after which
+    /// the select gives up. In that case the `branch` register is set to
+    /// [Select::TIMEOUT_BRANCH][crate::Select::TIMEOUT_BRANCH] and a unit is
+    /// pushed on the stack. This requires a timer to be configured with
+    /// [Vm::set_timer][crate::Vm::set_timer].
+    ///
     /// # Operation
     ///
     /// ```text
     /// <future...>
+    /// <duration>?
     /// => <value>
     /// ```
     Select {
         /// The number of futures to poll.
         len: usize,
+        /// If the select has a timeout.
+        timeout: bool,
     },
     /// Load the given function by hash and push onto the stack.
     ///
@@ -377,6 +386,9 @@ pub enum Inst {
     /// Drop the value in the given frame offset, cleaning out it's slot in
     /// memory.
     ///
+    /// If this was the last reference to an external value, its
+    /// [DROP][crate::DROP] protocol is called.
+    ///
     /// # Operation
     ///
     /// ```text
@@ -400,6 +412,20 @@ pub enum Inst {
         /// Offset to swap value from.
         offset: usize,
     },
+    /// Swap the values at the two given offsets relative to the current call
+    /// frame.
+    ///
+    /// # Operation
+    ///
+    /// ```text
+    /// => *noop*
+    /// ```
+    Swap {
+        /// Offset of the first value.
+        a: usize,
+        /// Offset of the second value.
+        b: usize,
+    },
     /// Pop the current stack frame and restore the instruction pointer from it.
     ///
     /// The stack frame will be cleared, and the value on the top of the stack
@@ -516,6 +542,19 @@ pub enum Inst {
         /// The size of the tuple.
         count: usize,
     },
+    /// Construct a range from the two integers on top of the stack.
+    ///
+    /// # Operation
+    ///
+    /// ```text
+    /// <end>
+    /// <start>
+    /// => <range>
+    /// ```
+    Range {
+        /// The limits of the range.
+        limits: RangeLimits,
+    },
     /// Take the tuple that is on top of the stack and push its content onto the
     /// stack.
     ///
@@ -575,6 +614,22 @@ pub enum Inst {
         /// The static slot of the object keys.
         slot: usize,
     },
+    /// Pop the base of an object spread and push a copy of it, so that fields
+    /// can be assigned to it without modifying the base.
+    ///
+    /// The base must be an anonymous object if `hash` is the type hash of
+    /// objects, or a struct of the given type otherwise.
+    ///
+    /// # Operation
+    ///
+    /// ```text
+    /// <value>
+    /// => <object>
+    /// ```
+    Spread {
+        /// The type of the object to construct.
+        hash: Hash,
+    },
     /// Construct a push an object variant of the given type onto the stack. The
     /// type is an empty struct.
     ///
@@ -870,6 +925,100 @@ impl Inst {
             value: InstValue::Float(v),
         }
     }
+
+    /// Get the name of the instruction, which is the name of its variant.
+    ///
+    /// This is used to group instructions when profiling.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Not { .. } => "Not",
+            Self::Neg { .. } => "Neg",
+            Self::Closure { .. } => "Closure",
+            Self::Call { .. } => "Call",
+            Self::CallInstance { .. } => "CallInstance",
+            Self::LoadInstanceFn { .. } => "LoadInstanceFn",
+            Self::CallFn { .. } => "CallFn",
+            Self::IndexGet { .. } => "IndexGet",
+            Self::TupleIndexGet { .. } => "TupleIndexGet",
+            Self::TupleIndexSet { .. } => "TupleIndexSet",
+            Self::TupleIndexGetAt { .. } => "TupleIndexGetAt",
+            Self::ObjectIndexGet { .. } => "ObjectIndexGet",
+            Self::ObjectIndexGetAt { .. } => "ObjectIndexGetAt",
+            Self::IndexSet { .. } => "IndexSet",
+            Self::Await { .. } => "Await",
+            Self::Select { .. } => "Select",
+            Self::LoadFn { .. } => "LoadFn",
+            Self::Push { .. } => "Push",
+            Self::Pop { .. } => "Pop",
+            Self::PopN { .. } => "PopN",
+            Self::PopAndJumpIfNot { .. } => "PopAndJumpIfNot",
+            Self::Clean { .. } => "Clean",
+            Self::Copy { .. } => "Copy",
+            Self::Move { .. } => "Move",
+            Self::Drop { .. } => "Drop",
+            Self::Dup { .. } => "Dup",
+            Self::Replace { .. } => "Replace",
+            Self::Swap { .. } => "Swap",
+            Self::Return { .. } => "Return",
+            Self::ReturnUnit { .. } => "ReturnUnit",
+            Self::Jump { .. } => "Jump",
+            Self::JumpIf { .. } => "JumpIf",
+            Self::JumpIfNot { .. } => "JumpIfNot",
+            Self::JumpIfOrPop { .. } => "JumpIfOrPop",
+            Self::JumpIfNotOrPop { .. } => "JumpIfNotOrPop",
+            Self::JumpIfBranch { .. } => "JumpIfBranch",
+            Self::Vec { .. } => "Vec",
+            Self::Tuple { .. } => "Tuple",
+            Self::Range { .. } => "Range",
+            Self::PushTuple { .. } => "PushTuple",
+            Self::Object { .. } => "Object",
+            Self::UnitStruct { .. } => "UnitStruct",
+            Self::Struct { .. } => "Struct",
+            Self::Spread { .. } => "Spread",
+            Self::UnitVariant { .. } => "UnitVariant",
+            Self::StructVariant { .. } => "StructVariant",
+            Self::String { .. } => "String",
+            Self::Bytes { .. } => "Bytes",
+            Self::StringConcat { .. } => "StringConcat",
+            Self::Format { .. } => "Format",
+            Self::IsUnit { .. } => "IsUnit",
+            Self::IsValue { .. } => "IsValue",
+            Self::Unwrap { .. } => "Unwrap",
+            Self::EqByte { .. } => "EqByte",
+            Self::EqCharacter { .. } => "EqCharacter",
+            Self::EqInteger { .. } => "EqInteger",
+            Self::EqStaticString { .. } => "EqStaticString",
+            Self::MatchSequence { .. } => "MatchSequence",
+            Self::MatchObject { .. } => "MatchObject",
+            Self::Yield { .. } => "Yield",
+            Self::YieldUnit { .. } => "YieldUnit",
+            Self::Op { .. } => "Op",
+            Self::Assign { .. } => "Assign",
+            Self::Panic { .. } => "Panic",
+        }
+    }
+
+    /// Test if the instruction allocates a new value which is pushed on top
+    /// of the stack.
+    pub(crate) fn is_allocation(&self) -> bool {
+        matches!(
+            self,
+            Self::Closure { .. }
+                | Self::Vec { .. }
+                | Self::Tuple { .. }
+                | Self::Range { .. }
+                | Self::Object { .. }
+                | Self::UnitStruct { .. }
+                | Self::Struct { .. }
+                | Self::Spread { .. }
+                | Self::UnitVariant { .. }
+                | Self::StructVariant { .. }
+                | Self::String { .. }
+                | Self::Bytes { .. }
+                | Self::StringConcat { .. }
+                | Self::Format { .. }
+        )
+    }
 }
 
 impl fmt::Display for Inst {
@@ -923,8 +1072,12 @@ impl fmt::Display for Inst {
             Self::Await => {
                 write!(fmt, "await")?;
             }
-            Self::Select { len } => {
+            Self::Select { len, timeout } => {
                 write!(fmt, "select {}", len)?;
+
+                if *timeout {
+                    write!(fmt, " (timeout)")?;
+                }
             }
             Self::LoadFn { hash } => {
                 write!(fmt, "load-fn {}", hash)?;
@@ -956,6 +1109,9 @@ impl fmt::Display for Inst {
             Self::Replace { offset } => {
                 write!(fmt, "replace {}", offset)?;
             }
+            Self::Swap { a, b } => {
+                write!(fmt, "swap {}, {}", a, b)?;
+            }
             Self::Return => {
                 write!(fmt, "return")?;
             }
@@ -986,6 +1142,9 @@ impl fmt::Display for Inst {
             Self::Tuple { count } => {
                 write!(fmt, "tuple {}", count)?;
             }
+            Self::Range { limits } => {
+                write!(fmt, "range {}", limits)?;
+            }
             Self::PushTuple => {
                 write!(fmt, "push-tuple")?;
             }
@@ -995,6 +1154,9 @@ impl fmt::Display for Inst {
             Self::Struct { hash, slot } => {
                 write!(fmt, "struct {}, {}", hash, slot)?;
             }
+            Self::Spread { hash } => {
+                write!(fmt, "spread {}", hash)?;
+            }
             Self::UnitVariant { hash } => {
                 write!(fmt, "unit-variant {}", hash)?;
             }
@@ -1197,6 +1359,8 @@ pub enum InstOp {
     Div,
     /// The remainder operation. `a % b`.
     Rem,
+    /// The exponentiation operation. `a ** b`.
+    Pow,
     /// The bitwise and operation. `a & b`.
     BitAnd,
     /// The bitwise xor operation. `a ^ b`.
@@ -1263,6 +1427,22 @@ pub enum InstOp {
     /// => <boolean>
     /// ```
     IsNot,
+    /// Test if the second item on the stack is contained in the collection at
+    /// the top of the stack.
+    ///
+    /// Vectors and tuples test if they contain an equal value, objects test if
+    /// they contain the given key, and strings test if they contain the given
+    /// substring or character. Other types are tested using their `contains`
+    /// instance function.
+    ///
+    /// # Operation
+    ///
+    /// ```text
+    /// <collection>
+    /// <value>
+    /// => <boolean>
+    /// ```
+    In,
     /// Pop two values from the stack and test if they are both boolean true.
     ///
     /// # Operation
@@ -1304,6 +1484,9 @@ impl fmt::Display for InstOp {
             Self::Rem => {
                 write!(f, "%")?;
             }
+            Self::Pow => {
+                write!(f, "**")?;
+            }
             Self::BitAnd => {
                 write!(f, "&")?;
             }
@@ -1343,6 +1526,9 @@ impl fmt::Display for InstOp {
             Self::IsNot => {
                 write!(f, "is not")?;
             }
+            Self::In => {
+                write!(f, "in")?;
+            }
             Self::And => {
                 write!(f, "&&")?;
             }
@@ -1403,7 +1589,10 @@ impl fmt::Display for InstValue {
             }
             Self::Char(v) => write!(f, "{:?}", v)?,
             Self::Integer(v) => write!(f, "{}", v)?,
-            Self::Float(v) => write!(f, "{}", v)?,
+            Self::Float(v) => {
+                let mut buffer = ryu::Buffer::new();
+                write!(f, "{}", buffer.format(*v))?
+            }
             Self::Type(v) => write!(f, "{}", v)?,
         }
 
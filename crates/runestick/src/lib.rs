@@ -61,6 +61,7 @@ mod awaited;
 pub mod budget;
 mod bytes;
 mod call;
+mod cancellation_token;
 mod compile_meta;
 mod const_value;
 pub mod debug;
@@ -83,6 +84,7 @@ mod names;
 mod object;
 mod panic;
 mod protocol;
+mod range;
 mod raw_str;
 mod select;
 mod serde;
@@ -149,13 +151,14 @@ pub use self::compile_meta::{
 pub use self::const_value::ConstValue;
 pub use self::format::{Format, FormatSpec};
 pub use self::from_value::{FromValue, UnsafeFromValue};
-pub use self::generator::Generator;
+pub use self::generator::{Generator, GeneratorIter};
 pub use self::generator_state::GeneratorState;
 pub use self::guarded_args::GuardedArgs;
 pub use self::id::Id;
 pub use self::label::{DebugLabel, Label};
 pub use self::module::{InstFnNameHash, Module};
 pub use self::named::Named;
+pub use self::range::{Range, RangeLimits};
 pub use self::raw_str::RawStr;
 pub use self::select::Select;
 pub use self::source::Source;
@@ -181,6 +184,7 @@ pub use crate::any::Any;
 pub use crate::awaited::Awaited;
 pub use crate::bytes::Bytes;
 pub use crate::call::Call;
+pub use crate::cancellation_token::CancellationToken;
 pub use crate::context::{Context, ContextError};
 pub use crate::debug::{DebugInfo, DebugInst};
 pub use crate::function::{Function, SyncFunction};
@@ -193,21 +197,24 @@ pub use crate::object::Object;
 pub use crate::panic::Panic;
 pub use crate::protocol::{
     Protocol, ADD, ADD_ASSIGN, BIT_AND, BIT_AND_ASSIGN, BIT_OR, BIT_OR_ASSIGN, BIT_XOR,
-    BIT_XOR_ASSIGN, DIV, DIV_ASSIGN, INDEX_GET, INDEX_SET, INTO_FUTURE, INTO_ITER, MUL, MUL_ASSIGN,
-    NEXT, REM, REM_ASSIGN, SHL, SHL_ASSIGN, SHR, SHR_ASSIGN, STRING_DISPLAY, SUB, SUB_ASSIGN,
+    BIT_XOR_ASSIGN, DIV, DIV_ASSIGN, DROP, INDEX_GET, INDEX_SET, INTO_FUTURE, INTO_ITER, MUL,
+    MUL_ASSIGN, NEXT, POW, REM, REM_ASSIGN, SHL, SHL_ASSIGN, SHR, SHR_ASSIGN, STRING_DISPLAY, SUB,
+    SUB_ASSIGN,
 };
-pub use crate::shared::{Mut, RawMut, RawRef, Ref, Shared, SharedPointerGuard};
+pub use crate::shared::{Mut, RawMut, RawRef, Ref, Shared, SharedPointerGuard, Weak};
 pub use crate::stack::{Stack, StackError};
 pub use crate::type_of::TypeOf;
-pub use crate::unit::{Unit, UnitFn, UnitTypeInfo};
+pub use crate::unit::{Unit, UnitFn, UnitFormatError, UnitTypeInfo};
 pub use crate::value::{
     Rtti, Struct, StructVariant, TupleStruct, TupleVariant, UnitStruct, UnitVariant, Value,
     VariantRtti,
 };
 pub use crate::vec_tuple::VecTuple;
-pub use crate::vm::{CallFrame, Vm};
+pub use crate::vm::{CallFrame, LiveSlot, Vm};
 pub use crate::vm_call::VmCall;
-pub use crate::vm_error::{VmError, VmErrorKind, VmIntegerRepr};
+pub use crate::vm_error::{
+    BacktraceFrame, ValuePath, ValuePathSegment, VmError, VmErrorKind, VmIntegerRepr,
+};
 pub use crate::vm_execution::VmExecution;
 pub use crate::vm_halt::{VmHalt, VmHaltInfo};
 pub(crate) use runestick_macros::__internal_impl_any;
@@ -5,8 +5,8 @@
 
 use crate::collections::HashMap;
 use crate::{
-    Future, Hash, IntoComponent, Named, Stack, ToValue, Type, TypeInfo, TypeOf, UnsafeFromValue,
-    VmError, VmErrorKind,
+    ConstValue, Future, Hash, IntoComponent, Named, Stack, ToValue, Type, TypeInfo, TypeOf,
+    UnsafeFromValue, VmError, VmErrorKind,
 };
 use std::any;
 use std::any::type_name;
@@ -109,6 +109,7 @@ impl ModuleAssociatedKind {
 pub(crate) struct ModuleAssociatedFn {
     pub(crate) handler: Arc<Handler>,
     pub(crate) args: Option<usize>,
+    pub(crate) is_async: bool,
     pub(crate) type_info: TypeInfo,
     pub(crate) name: String,
 }
@@ -123,6 +124,7 @@ pub(crate) struct ModuleAssocKey {
 pub(crate) struct ModuleFn {
     pub(crate) handler: Arc<Handler>,
     pub(crate) args: Option<usize>,
+    pub(crate) is_async: bool,
 }
 
 pub(crate) struct ModuleMacro {
@@ -138,6 +140,8 @@ pub struct Module {
     pub(crate) functions: HashMap<Item, ModuleFn>,
     /// Macro handlers.
     pub(crate) macros: HashMap<Item, ModuleMacro>,
+    /// Constant values.
+    pub(crate) constants: HashMap<Item, ConstValue>,
     /// Instance functions.
     pub(crate) associated_functions: HashMap<ModuleAssocKey, ModuleAssociatedFn>,
     /// Registered types.
@@ -146,6 +150,8 @@ pub struct Module {
     pub(crate) unit_type: Option<ModuleUnitType>,
     /// Registered generator state type.
     pub(crate) internal_enums: Vec<ModuleInternalEnum>,
+    /// If functions in this module can override existing functions.
+    pub(crate) overrides: bool,
 }
 
 impl Module {
@@ -159,10 +165,12 @@ impl Module {
             path: Item::of(path),
             functions: Default::default(),
             macros: Default::default(),
+            constants: Default::default(),
             associated_functions: Default::default(),
             types: Default::default(),
             unit_type: None,
             internal_enums: Vec::new(),
+            overrides: false,
         }
     }
 
@@ -171,6 +179,33 @@ impl Module {
         Self::default()
     }
 
+    /// Allow functions in this module to replace functions which are already
+    /// present in the [Context][crate::Context] it's installed into.
+    ///
+    /// By default, installing a function at an item which is already occupied
+    /// is an error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::{Context, Module};
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let mut context = Context::with_default_modules()?;
+    ///
+    /// let mut module = Module::new(&["std", "string"]);
+    /// module.function(&["String", "from_str"], |s: String| s.to_uppercase())?;
+    /// assert!(context.install(&module).is_err());
+    ///
+    /// module.allow_overrides();
+    /// context.install(&module)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn allow_overrides(&mut self) {
+        self.overrides = true;
+    }
+
     /// Register a type. Registering a type is mandatory in order to register
     /// instance functions using that type.
     ///
@@ -405,12 +440,51 @@ impl Module {
             ModuleFn {
                 handler: Arc::new(move |stack, args| f.fn_call(stack, args)),
                 args: Some(Func::args()),
+                is_async: false,
             },
         );
 
         Ok(())
     }
 
+    /// Register a constant value, which will be available to scripts as a
+    /// compile-time `const`.
+    ///
+    /// The value is converted when it's registered, so it must be
+    /// representable as a [ConstValue].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> runestick::Result<()> {
+    /// let mut module = runestick::Module::new(&["config"]);
+    ///
+    /// module.constant(&["MAX_RETRIES"], 5i64)?;
+    /// module.constant(&["NAME"], String::from("rune"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn constant<N, V>(&mut self, name: N, value: V) -> Result<(), ContextError>
+    where
+        N: IntoIterator,
+        N::Item: IntoComponent,
+        V: ToValue,
+    {
+        let name = Item::of(name);
+
+        if self.constants.contains_key(&name) {
+            return Err(ContextError::ConflictingConstantName { name });
+        }
+
+        let const_value = value
+            .to_value()
+            .and_then(ConstValue::from_value)
+            .map_err(|error| ContextError::ValueError { error })?;
+
+        self.constants.insert(name, const_value);
+        Ok(())
+    }
+
     /// Register a native macro handler.
     pub fn macro_<N, M, A, O>(&mut self, name: N, f: M) -> Result<(), ContextError>
     where
@@ -477,6 +551,7 @@ impl Module {
             ModuleFn {
                 handler: Arc::new(move |stack, args| f.fn_call(stack, args)),
                 args: Some(Func::args()),
+                is_async: true,
             },
         );
 
@@ -502,6 +577,7 @@ impl Module {
             ModuleFn {
                 handler: Arc::new(move |stack, args| f(stack, args)),
                 args: None,
+                is_async: false,
             },
         );
 
@@ -592,6 +668,7 @@ impl Module {
         let instance_function = ModuleAssociatedFn {
             handler,
             args: Some(Func::args()),
+            is_async: false,
             type_info,
             name,
         };
@@ -653,6 +730,7 @@ impl Module {
         let instance_function = ModuleAssociatedFn {
             handler,
             args: Some(Func::args()),
+            is_async: true,
             type_info,
             name,
         };
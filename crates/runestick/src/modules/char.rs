@@ -0,0 +1,42 @@
+//! The `std::char` module.
+
+use crate::{ContextError, Module};
+
+/// Convert a character to uppercase.
+///
+/// Some characters uppercase into multiple characters, like `ß` which becomes
+/// `SS`. Since the common case is a single character, this returns a character
+/// and in that case only the first character of the mapping is used.
+fn to_uppercase(c: char) -> char {
+    c.to_uppercase().next().unwrap_or(c)
+}
+
+/// Convert a character to lowercase.
+///
+/// Like [to_uppercase], this only uses the first character of mappings which
+/// result in multiple characters.
+fn to_lowercase(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+/// Convert a character into a string.
+fn to_string(c: char) -> String {
+    c.to_string()
+}
+
+/// Construct the `std::char` module.
+pub fn module() -> Result<Module, ContextError> {
+    let mut module = Module::new(&["std", "char"]);
+
+    module.inst_fn("is_alphabetic", char::is_alphabetic)?;
+    module.inst_fn("is_alphanumeric", char::is_alphanumeric)?;
+    module.inst_fn("is_numeric", char::is_numeric)?;
+    module.inst_fn("is_whitespace", char::is_whitespace)?;
+    module.inst_fn("is_lowercase", char::is_lowercase)?;
+    module.inst_fn("is_uppercase", char::is_uppercase)?;
+
+    module.inst_fn("to_uppercase", to_uppercase)?;
+    module.inst_fn("to_lowercase", to_lowercase)?;
+    module.inst_fn("to_string", to_string)?;
+    Ok(module)
+}
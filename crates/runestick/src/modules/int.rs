@@ -18,16 +18,19 @@ pub fn module() -> Result<Module, ContextError> {
     module.inst_fn("checked_div", i64::checked_div)?;
     module.inst_fn("checked_mul", i64::checked_mul)?;
     module.inst_fn("checked_rem", i64::checked_rem)?;
+    module.inst_fn("checked_neg", i64::checked_neg)?;
 
     module.inst_fn("wrapping_add", i64::wrapping_add)?;
     module.inst_fn("wrapping_sub", i64::wrapping_sub)?;
     module.inst_fn("wrapping_div", i64::wrapping_div)?;
     module.inst_fn("wrapping_mul", i64::wrapping_mul)?;
     module.inst_fn("wrapping_rem", i64::wrapping_rem)?;
+    module.inst_fn("wrapping_neg", i64::wrapping_neg)?;
 
     module.inst_fn("saturating_add", i64::saturating_add)?;
     module.inst_fn("saturating_sub", i64::saturating_sub)?;
     module.inst_fn("saturating_mul", i64::saturating_mul)?;
+    module.inst_fn("saturating_neg", i64::saturating_neg)?;
     module.inst_fn("saturating_abs", i64::saturating_abs)?;
     module.inst_fn("saturating_pow", i64::saturating_pow)?;
 
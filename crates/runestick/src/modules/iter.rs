@@ -1,76 +1,113 @@
 //! The `std::iter` module.
 
-use crate::{ContextError, Module};
+use crate::{ContextError, Module, Range, RangeLimits};
+use std::fmt;
+use std::fmt::Write as _;
+use std::iter::Rev;
 
 /// Construct the `std::iter` module.
 pub fn module() -> Result<Module, ContextError> {
     let mut module = Module::new(&["std", "iter"]);
     module.ty::<Range>()?;
-    module.ty::<Rev>()?;
-    module.function(&["range"], Range::new)?;
-    module.inst_fn(crate::INTO_ITER, Range::into_iter)?;
-    module.inst_fn(crate::NEXT, Range::next)?;
-    module.inst_fn("rev", Range::rev)?;
-    module.inst_fn(crate::INTO_ITER, Rev::into_iter)?;
-    module.inst_fn(crate::NEXT, Rev::next)?;
+    module.ty::<Iter>()?;
+    module.ty::<Rev<Iter>>()?;
+    module.function(&["range"], range)?;
+    module.inst_fn("contains", Range::contains)?;
+    module.inst_fn("is_empty", Range::is_empty)?;
+    module.inst_fn("iter", Iter::new)?;
+    module.inst_fn("rev", range_rev)?;
+    module.inst_fn(crate::INTO_ITER, Iter::new)?;
+    module.inst_fn(crate::STRING_DISPLAY, format_range)?;
+
+    module.inst_fn("next", Iter::next)?;
+    module.inst_fn("next_back", Iter::next_back)?;
+    module.inst_fn("rev", Iter::rev)?;
+    module.inst_fn(crate::NEXT, Iter::next)?;
+    module.inst_fn(crate::INTO_ITER, Iter::into_iter)?;
+
+    module.inst_fn("next", Rev::<Iter>::next)?;
+    module.inst_fn("next_back", Rev::<Iter>::next_back)?;
+    module.inst_fn(crate::NEXT, Rev::<Iter>::next)?;
+    module.inst_fn(crate::INTO_ITER, Rev::<Iter>::into_iter)?;
     Ok(module)
 }
 
-#[derive(Debug)]
-struct Rev {
-    current: i64,
-    start: i64,
+/// Construct a half-open range, equivalent to `start..end`.
+fn range(start: i64, end: i64) -> Range {
+    Range::new(start, end, RangeLimits::HalfOpen)
 }
 
-impl Iterator for Rev {
-    type Item = i64;
-
-    fn next(&mut self) -> Option<i64> {
-        if self.current <= self.start {
-            return None;
-        }
+fn range_rev(range: &Range) -> Rev<Iter> {
+    Iter::new(range).rev()
+}
 
-        self.current -= 1;
-        Some(self.current)
-    }
+fn format_range(range: &Range, buf: &mut String) -> fmt::Result {
+    write!(buf, "{}", range)
 }
 
+/// An iterator over a range.
 #[derive(Debug)]
-struct Range {
-    current: i64,
-    end: i64,
+pub struct Iter {
+    /// The next value to produce from the front, if not exhausted.
+    front: i64,
+    /// The next value to produce from the back, if not exhausted.
+    back: i64,
+    /// Indicates if the iterator is exhausted.
+    done: bool,
 }
 
-impl Range {
-    fn new(start: i64, end: i64) -> Self {
+impl Iter {
+    fn new(range: &Range) -> Self {
+        let back = match range.limits {
+            RangeLimits::HalfOpen => range.end.wrapping_sub(1),
+            RangeLimits::Closed => range.end,
+        };
+
         Self {
-            current: start,
-            end,
+            front: range.start,
+            back,
+            done: range.is_empty(),
         }
     }
+}
 
-    fn rev(self) -> Rev {
-        Rev {
-            current: self.end,
-            start: self.current,
+impl Iterator for Iter {
+    type Item = i64;
+
+    fn next(&mut self) -> Option<i64> {
+        if self.done {
+            return None;
+        }
+
+        let value = self.front;
+
+        if self.front == self.back {
+            self.done = true;
+        } else {
+            self.front += 1;
         }
+
+        Some(value)
     }
 }
 
-impl Iterator for Range {
-    type Item = i64;
+impl DoubleEndedIterator for Iter {
+    fn next_back(&mut self) -> Option<i64> {
+        if self.done {
+            return None;
+        }
 
-    fn next(&mut self) -> Option<i64> {
-        let value = self.current;
+        let value = self.back;
 
-        if self.current < self.end {
-            self.current += 1;
-            return Some(value);
+        if self.front == self.back {
+            self.done = true;
+        } else {
+            self.back -= 1;
         }
 
-        None
+        Some(value)
     }
 }
 
-crate::__internal_impl_any!(Range);
-crate::__internal_impl_any!(Rev);
+crate::__internal_impl_any!(Iter, "Iter");
+crate::__internal_impl_any!(Rev<Iter>, "Rev");
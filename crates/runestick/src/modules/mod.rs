@@ -2,6 +2,7 @@
 //! machines.
 
 pub mod bytes;
+pub mod char;
 pub mod core;
 pub mod float;
 pub mod fmt;
@@ -15,4 +16,7 @@ pub mod option;
 pub mod result;
 pub mod stream;
 pub mod string;
+pub mod test;
+pub mod time;
+pub mod tuple;
 pub mod vec;
@@ -1,6 +1,6 @@
 //! The `std::object` module.
 
-use crate::{ContextError, Module, Object, Value};
+use crate::{ContextError, Module, Object, Value, Vec};
 use std::iter::Rev;
 
 /// Construct the `std::object` module.
@@ -16,6 +16,9 @@ pub fn module() -> Result<Module, ContextError> {
     module.inst_fn("clear", Object::clear)?;
     module.inst_fn("contains_key", contains_key)?;
     module.inst_fn("get", get)?;
+    module.inst_fn("keys", keys)?;
+    module.inst_fn("values", values)?;
+    module.inst_fn("iter", object_iter)?;
 
     module.inst_fn(crate::INTO_ITER, object_iter)?;
     module.inst_fn("next", Iter::next)?;
@@ -55,11 +58,21 @@ fn object_iter(object: &Object) -> Iter {
         iter: object
             .iter()
             .map(|(k, v)| (k.clone(), v.clone()))
-            .collect::<Vec<_>>()
+            .collect::<std::vec::Vec<_>>()
             .into_iter(),
     }
 }
 
+/// Collect the keys of an object into a vector.
+fn keys(object: &Object) -> Vec {
+    object.iter().map(|(k, _)| Value::from(k.clone())).collect()
+}
+
+/// Collect the values of an object into a vector.
+fn values(object: &Object) -> Vec {
+    object.iter().map(|(_, v)| v.clone()).collect()
+}
+
 fn contains_key(object: &Object, key: &str) -> bool {
     object.contains_key(key)
 }
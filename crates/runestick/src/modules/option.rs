@@ -9,6 +9,8 @@ pub fn module() -> Result<Module, ContextError> {
     module.inst_fn("is_some", Option::<Value>::is_some)?;
     module.inst_fn("unwrap_or_else", unwrap_or_else_impl)?;
     module.inst_fn("transpose", transpose_impl)?;
+    module.inst_fn("map", map_impl)?;
+    module.inst_fn("ok_or", ok_or_impl)?;
     Ok(module)
 }
 
@@ -22,6 +24,19 @@ fn unwrap_or_else_impl(this: &Option<Value>, default: Function) -> Result<Value,
     Ok(default.call(())?)
 }
 
+/// Map the contained value, if any, through the given function.
+fn map_impl(this: &Option<Value>, then: Function) -> Result<Option<Value>, VmError> {
+    match this {
+        Some(value) => Ok(Some(then.call::<_, Value>((value.clone(),))?)),
+        None => Ok(None),
+    }
+}
+
+/// Convert into a `Result`, using the given value as the error if empty.
+fn ok_or_impl(this: &Option<Value>, err: Value) -> Result<Value, Value> {
+    this.clone().ok_or(err)
+}
+
 /// Transpose functions, translates an Option<Result<T, E>> into a `Result<Option<T>, E>`.
 fn transpose_impl(this: &Option<Value>) -> Result<Value, VmError> {
     Ok(Value::from(Shared::new(match this.clone() {
@@ -1,6 +1,6 @@
 //! The `std::result` module.
 
-use crate::{ContextError, Module, Value};
+use crate::{ContextError, Function, Module, Value, VmError};
 
 /// Construct the `std::result` module.
 pub fn module() -> Result<Module, ContextError> {
@@ -8,6 +8,9 @@ pub fn module() -> Result<Module, ContextError> {
     module.result(&["Result"])?;
     module.inst_fn("is_ok", is_ok)?;
     module.inst_fn("is_err", is_err)?;
+    module.inst_fn("unwrap_or", unwrap_or)?;
+    module.inst_fn("ok", ok)?;
+    module.inst_fn("map", map)?;
     Ok(module)
 }
 
@@ -18,3 +21,19 @@ fn is_ok(result: &Result<Value, Value>) -> bool {
 fn is_err(result: &Result<Value, Value>) -> bool {
     result.is_err()
 }
+
+fn unwrap_or(result: &Result<Value, Value>, default: Value) -> Value {
+    result.clone().unwrap_or(default)
+}
+
+fn ok(result: &Result<Value, Value>) -> Option<Value> {
+    result.clone().ok()
+}
+
+/// Map the ok value through the given function, leaving errors untouched.
+fn map(result: &Result<Value, Value>, then: Function) -> Result<Result<Value, Value>, VmError> {
+    match result {
+        Ok(value) => Ok(Ok(then.call::<_, Value>((value.clone(),))?)),
+        Err(error) => Ok(Err(error.clone())),
+    }
+}
@@ -1,16 +1,21 @@
 //! The `std::string` module.
 
-use crate::{Bytes, ContextError, Module};
+use crate::{Bytes, ContextError, Module, Value, Vec, VmError};
+use std::string::FromUtf8Error;
 
 /// Construct the `std::string` module.
 pub fn module() -> Result<Module, ContextError> {
     let mut module = Module::new(&["std", "string"]);
 
     module.ty::<String>()?;
+    module.ty::<Chars>()?;
+    module.ty::<ByteIter>()?;
+    module.ty::<FromUtf8Error>()?;
 
     module.function(&["String", "from_str"], <String as From<&str>>::from)?;
     module.function(&["String", "new"], String::new)?;
     module.function(&["String", "with_capacity"], String::with_capacity)?;
+    module.function(&["String", "from_utf8"], from_utf8)?;
 
     module.inst_fn("len", String::len)?;
     module.inst_fn("capacity", String::capacity)?;
@@ -20,11 +25,26 @@ pub fn module() -> Result<Module, ContextError> {
     module.inst_fn("reserve", String::reserve)?;
     module.inst_fn("reserve_exact", String::reserve_exact)?;
     module.inst_fn("into_bytes", into_bytes)?;
+    module.inst_fn("as_bytes", as_bytes)?;
     module.inst_fn("clone", String::clone)?;
     module.inst_fn("shrink_to_fit", String::shrink_to_fit)?;
     module.inst_fn("char_at", char_at)?;
+    module.inst_fn("split", split)?;
+    module.inst_fn("trim", trim)?;
+    module.inst_fn("replace", str::replace::<&str>)?;
     module.inst_fn(crate::ADD, add)?;
     module.inst_fn(crate::ADD_ASSIGN, String::push_str)?;
+
+    module.inst_fn("chars", chars)?;
+    module.inst_fn(crate::INTO_ITER, chars)?;
+    module.inst_fn("next", Chars::next)?;
+    module.inst_fn(crate::NEXT, Chars::next)?;
+    module.inst_fn(crate::INTO_ITER, Chars::into_iter)?;
+
+    module.inst_fn("bytes", bytes)?;
+    module.inst_fn("next", ByteIter::next)?;
+    module.inst_fn(crate::NEXT, ByteIter::next)?;
+    module.inst_fn(crate::INTO_ITER, ByteIter::into_iter)?;
     Ok(module)
 }
 
@@ -36,6 +56,16 @@ fn into_bytes(s: String) -> Bytes {
     Bytes::from_vec(s.into_bytes())
 }
 
+/// Copy the bytes of a string into a byte string.
+fn as_bytes(s: &str) -> Bytes {
+    Bytes::from_vec(s.as_bytes().to_vec())
+}
+
+/// Convert a byte string into a string, erroring if it is not valid UTF-8.
+fn from_utf8(bytes: &[u8]) -> Result<String, FromUtf8Error> {
+    String::from_utf8(bytes.to_vec())
+}
+
 fn char_at(s: &str, index: usize) -> Result<Option<char>, NotCharBoundary> {
     if !s.is_char_boundary(index) {
         return Err(NotCharBoundary(()));
@@ -44,6 +74,24 @@ fn char_at(s: &str, index: usize) -> Result<Option<char>, NotCharBoundary> {
     Ok(s[index..].chars().next())
 }
 
+/// Split a string by a separator into a vector of strings.
+fn split(s: &str, sep: &str) -> Result<Vec, VmError> {
+    // NB: splitting on an empty separator would produce an empty string
+    // between every character, which is never what's intended.
+    if sep.is_empty() {
+        return Err(VmError::panic("cannot split on an empty separator"));
+    }
+
+    Ok(s.split(sep)
+        .map(|part| Value::from(part.to_owned()))
+        .collect())
+}
+
+/// Trim whitespace from both ends of a string.
+fn trim(s: &str) -> String {
+    s.trim().to_owned()
+}
+
 /// The add operation for strings.
 fn add(a: &str, b: &str) -> String {
     let mut string = String::with_capacity(a.len() + b.len());
@@ -52,4 +100,49 @@ fn add(a: &str, b: &str) -> String {
     string
 }
 
+/// An iterator over the characters of a string.
+pub struct Chars {
+    string: String,
+    index: usize,
+}
+
+impl Iterator for Chars {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.string[self.index..].chars().next()?;
+        self.index += c.len_utf8();
+        Some(c)
+    }
+}
+
+fn chars(s: &str) -> Chars {
+    Chars {
+        string: s.to_owned(),
+        index: 0,
+    }
+}
+
+/// An iterator over the bytes of a string.
+pub struct ByteIter {
+    iter: std::vec::IntoIter<u8>,
+}
+
+impl Iterator for ByteIter {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Value> {
+        Some(Value::Byte(self.iter.next()?))
+    }
+}
+
+fn bytes(s: &str) -> ByteIter {
+    ByteIter {
+        iter: s.as_bytes().to_vec().into_iter(),
+    }
+}
+
 crate::__internal_impl_any!(NotCharBoundary);
+crate::__internal_impl_any!(FromUtf8Error);
+crate::__internal_impl_any!(Chars, "Chars");
+crate::__internal_impl_any!(ByteIter, "Bytes");
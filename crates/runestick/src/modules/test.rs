@@ -0,0 +1,32 @@
+//! The `std::test` module.
+
+use crate::{ContextError, Module, Value, VmError};
+
+/// Construct the `std::test` module.
+pub fn module() -> Result<Module, ContextError> {
+    let mut module = Module::new(&["std", "test"]);
+    module.function(&["assert"], assert_impl)?;
+    module.function(&["assert_eq"], assert_eq_impl)?;
+    Ok(module)
+}
+
+/// Panic unless the given condition holds.
+fn assert_impl(cond: bool) -> Result<(), VmError> {
+    if !cond {
+        return Err(VmError::panic("assertion failed"));
+    }
+
+    Ok(())
+}
+
+/// Panic unless the two values are equal, as determined by `==`.
+fn assert_eq_impl(left: Value, right: Value) -> Result<(), VmError> {
+    if !Value::value_ptr_eq(&left, &right)? {
+        return Err(VmError::panic(format!(
+            "assertion failed (left == right): left: {:?}, right: {:?}",
+            left, right
+        )));
+    }
+
+    Ok(())
+}
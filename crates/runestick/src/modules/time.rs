@@ -0,0 +1,22 @@
+//! The `std::time` module.
+
+use crate::{ContextError, Module};
+use std::time::Duration;
+
+/// Construct the `std::time` module.
+pub fn module() -> Result<Module, ContextError> {
+    let mut module = Module::new(&["std", "time"]);
+    module.ty::<Duration>()?;
+    module.function(&["Duration", "from_secs"], Duration::from_secs)?;
+    module.function(&["Duration", "from_millis"], Duration::from_millis)?;
+    module.inst_fn("as_secs", Duration::as_secs)?;
+    module.inst_fn("as_millis", as_millis)?;
+    Ok(module)
+}
+
+/// Get the number of whole milliseconds in the duration.
+fn as_millis(duration: &Duration) -> u64 {
+    duration.as_millis() as u64
+}
+
+crate::__internal_impl_any!(Duration);
@@ -0,0 +1,83 @@
+//! The `std::tuple` module.
+
+use crate::{ContextError, Module, Shared, Tuple, Value, VmError};
+
+/// Construct the `std::tuple` module.
+pub fn module() -> Result<Module, ContextError> {
+    let mut module = Module::new(&["std", "tuple"]);
+
+    module.ty::<Tuple>()?;
+    module.ty::<Iter>()?;
+
+    module.inst_fn("len", Tuple::len)?;
+    module.inst_fn("get", get)?;
+    module.inst_fn("first", first)?;
+    module.inst_fn("last", last)?;
+    module.inst_fn("iter", Iter::new)?;
+    module.inst_fn(crate::ADD, concat)?;
+
+    module.inst_fn(crate::INTO_ITER, Iter::new)?;
+    module.inst_fn("next", Iter::next)?;
+    module.inst_fn(crate::NEXT, Iter::next)?;
+    module.inst_fn(crate::INTO_ITER, Iter::into_iter)?;
+    Ok(module)
+}
+
+/// Get the value at the given index, or `None` if it's out of range.
+fn get(tuple: &Tuple, index: i64) -> Option<Value> {
+    if index < 0 {
+        return None;
+    }
+
+    tuple.get(index as usize).cloned()
+}
+
+/// Get the first value of the tuple, or `None` if it's empty.
+fn first(tuple: &Tuple) -> Option<Value> {
+    tuple.first().cloned()
+}
+
+/// Get the last value of the tuple, or `None` if it's empty.
+fn last(tuple: &Tuple) -> Option<Value> {
+    tuple.last().cloned()
+}
+
+/// Concatenate two tuples into a new tuple.
+fn concat(a: &Tuple, b: &Tuple) -> Tuple {
+    let mut values = Vec::with_capacity(a.len() + b.len());
+    values.extend(a.iter().cloned());
+    values.extend(b.iter().cloned());
+    Tuple::from(values)
+}
+
+/// An iterator over a tuple.
+///
+/// Values are cloned out of the tuple as they are iterated over.
+pub struct Iter {
+    tuple: Shared<Tuple>,
+    index: usize,
+}
+
+impl Iter {
+    fn new(tuple: Shared<Tuple>) -> Self {
+        Self { tuple, index: 0 }
+    }
+
+    fn next(&mut self) -> Result<Option<Value>, VmError> {
+        let tuple = self.tuple.borrow_ref()?;
+
+        let value = match tuple.get(self.index) {
+            Some(value) => value.clone(),
+            None => return Ok(None),
+        };
+
+        self.index += 1;
+        Ok(Some(value))
+    }
+
+    fn into_iter(self) -> Self {
+        self
+    }
+}
+
+crate::__internal_impl_any!(Iter, "Iter");
@@ -1,6 +1,7 @@
 //! The `std::vec` module.
 
-use crate::{ContextError, Module, Value, Vec};
+use crate::{ContextError, Function, Module, Value, Vec, VmError};
+use std::cmp::Ordering;
 use std::iter::Rev;
 
 /// Construct the `std::vec` module.
@@ -17,6 +18,8 @@ pub fn module() -> Result<Module, ContextError> {
     module.inst_fn("push", Vec::push)?;
     module.inst_fn("clear", Vec::clear)?;
     module.inst_fn("pop", Vec::pop)?;
+    module.inst_fn("sort", sort)?;
+    module.inst_fn("sort_by", sort_by)?;
 
     module.inst_fn(crate::INTO_ITER, vec_iter)?;
     module.inst_fn("next", Iter::next)?;
@@ -56,5 +59,49 @@ fn vec_iter(vec: &[Value]) -> Iter {
     }
 }
 
+/// Sort a vector in place, using the natural ordering of its values.
+fn sort(vec: &mut Vec) -> Result<(), VmError> {
+    sort_with(vec, |a, b| {
+        // NB: values which can't be ordered, like `NaN`, are treated as equal.
+        Ok(Value::partial_cmp(a, b)?.unwrap_or(Ordering::Equal))
+    })
+}
+
+/// Sort a vector in place, using a comparison function which returns a
+/// negative, zero, or positive integer.
+fn sort_by(vec: &mut Vec, comparator: Function) -> Result<(), VmError> {
+    sort_with(vec, |a, b| {
+        let ordering: i64 = comparator.call((a.clone(), b.clone()))?;
+        Ok(ordering.cmp(&0))
+    })
+}
+
+/// Sort with a fallible comparison, returning the first error raised.
+fn sort_with<F>(vec: &mut Vec, mut cmp: F) -> Result<(), VmError>
+where
+    F: FnMut(&Value, &Value) -> Result<Ordering, VmError>,
+{
+    let mut error = None;
+
+    vec.sort_by(|a, b| {
+        if error.is_some() {
+            return Ordering::Equal;
+        }
+
+        match cmp(a, b) {
+            Ok(ordering) => ordering,
+            Err(e) => {
+                error = Some(e);
+                Ordering::Equal
+            }
+        }
+    });
+
+    match error {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
+}
+
 crate::__internal_impl_any!(Iter, "Iter");
 crate::__internal_impl_any!(Rev<Iter>, "Rev");
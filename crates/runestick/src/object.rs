@@ -6,6 +6,11 @@ use std::borrow;
 use std::cmp;
 use std::fmt;
 use std::hash;
+use std::iter;
+
+/// The map used to store the entries of an object, which preserves the order
+/// in which they were inserted.
+type Inner = indexmap::IndexMap<String, Value>;
 
 /// An owning iterator over the entries of a `Object`.
 ///
@@ -14,7 +19,7 @@ use std::hash;
 ///
 /// [`into_iter`]: struct.Object.html#method.into_iter
 /// [`Object`]: struct.Object.html
-pub type IntoIter = crate::collections::hash_map::IntoIter<String, Value>;
+pub type IntoIter = indexmap::map::IntoIter<String, Value>;
 
 /// A mutable iterator over the entries of a `Object`.
 ///
@@ -23,7 +28,7 @@ pub type IntoIter = crate::collections::hash_map::IntoIter<String, Value>;
 ///
 /// [`iter_mut`]: struct.Object.html#method.iter_mut
 /// [`Object`]: struct.Object.html
-pub type IterMut<'a> = crate::collections::hash_map::IterMut<'a, String, Value>;
+pub type IterMut<'a> = indexmap::map::IterMut<'a, String, Value>;
 
 /// An iterator over the entries of a `Object`.
 ///
@@ -32,10 +37,13 @@ pub type IterMut<'a> = crate::collections::hash_map::IterMut<'a, String, Value>;
 ///
 /// [`iter`]: struct.Object.html#method.iter
 /// [`Object`]: struct.Object.html
-pub type Iter<'a> = crate::collections::hash_map::Iter<'a, String, Value>;
+pub type Iter<'a> = indexmap::map::Iter<'a, String, Value>;
 
 /// Struct representing a dynamic anonymous object.
 ///
+/// Entries are iterated over in the order in which they were inserted, so
+/// that output produced from objects is reproducible.
+///
 /// # Examples
 ///
 /// ```rust
@@ -55,14 +63,14 @@ pub type Iter<'a> = crate::collections::hash_map::Iter<'a, String, Value>;
 #[derive(Default, Clone)]
 #[repr(transparent)]
 pub struct Object {
-    inner: HashMap<String, Value>,
+    inner: Inner,
 }
 
 impl Object {
     /// Construct a new object.
     pub fn new() -> Self {
         Self {
-            inner: HashMap::new(),
+            inner: Inner::new(),
         }
     }
 
@@ -121,12 +129,14 @@ impl Object {
 
     /// Removes a key from the object, returning the value at the key if the key
     /// was previously in the object.
+    ///
+    /// This preserves the order of the remaining entries.
     pub fn remove<Q: ?Sized>(&mut self, k: &Q) -> Option<Value>
     where
         String: borrow::Borrow<Q>,
         Q: hash::Hash + cmp::Eq,
     {
-        self.inner.remove(k)
+        self.inner.shift_remove(k)
     }
 
     /// Inserts a key-value pair into the dynamic object, converting it as
@@ -153,22 +163,22 @@ impl Object {
     /// Construct a new object with the given capacity.
     pub fn with_capacity(cap: usize) -> Self {
         Self {
-            inner: HashMap::with_capacity(cap),
+            inner: Inner::with_capacity(cap),
         }
     }
 
     /// Convert into inner.
-    pub fn into_inner(self) -> HashMap<String, Value> {
+    pub fn into_inner(self) -> indexmap::IndexMap<String, Value> {
         self.inner
     }
 
-    /// An iterator visiting all key-value pairs in arbitrary order.
+    /// An iterator visiting all key-value pairs in insertion order.
     /// The iterator element type is `(&'a String, &'a Value)`.
     pub fn iter(&self) -> Iter<'_> {
         self.inner.iter()
     }
 
-    /// An iterator visiting all key-value pairs in arbitrary order,
+    /// An iterator visiting all key-value pairs in insertion order,
     /// with mutable references to the values.
     /// The iterator element type is `(&'a String, &'a mut Value)`.
     pub fn iter_mut(&mut self) -> IterMut<'_> {
@@ -199,7 +209,7 @@ impl IntoIterator for Object {
     type IntoIter = IntoIter;
 
     /// Creates a consuming iterator, that is, one that moves each key-value
-    /// pair out of the object in arbitrary order. The object cannot be used
+    /// pair out of the object in insertion order. The object cannot be used
     /// after calling this.
     fn into_iter(self) -> Self::IntoIter {
         self.inner.into_iter()
@@ -212,8 +222,24 @@ impl fmt::Debug for Object {
     }
 }
 
+impl iter::FromIterator<(String, Value)> for Object {
+    fn from_iter<T: IntoIterator<Item = (String, Value)>>(iter: T) -> Self {
+        Self {
+            inner: iter.into_iter().collect(),
+        }
+    }
+}
+
 impl From<HashMap<String, Value>> for Object {
     fn from(object: HashMap<String, Value>) -> Self {
+        Self {
+            inner: object.into_iter().collect(),
+        }
+    }
+}
+
+impl From<indexmap::IndexMap<String, Value>> for Object {
+    fn from(object: indexmap::IndexMap<String, Value>) -> Self {
         Self { inner: object }
     }
 }
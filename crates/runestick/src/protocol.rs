@@ -116,6 +116,12 @@ pub const REM_ASSIGN: Protocol = Protocol {
     hash: Hash::new(0x3a8695980e77baf4),
 };
 
+/// The function to implement for the exponentiation operation.
+pub const POW: Protocol = Protocol {
+    name: "**",
+    hash: Hash::new(0xdc575d7aa0e0deaf),
+};
+
 /// The function to implement for the bitwise and operation.
 pub const BIT_AND: Protocol = Protocol {
     name: "&",
@@ -199,3 +205,10 @@ pub const INTO_FUTURE: Protocol = Protocol {
     name: "into_future",
     hash: Hash::new(0x596e6428deabfda2),
 };
+
+/// Function called when the last local referencing an external value goes
+/// out of scope.
+pub const DROP: Protocol = Protocol {
+    name: "drop",
+    hash: Hash::new(0x7cbdb2fcb42c3f63),
+};
@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The limits of a range, indicating if the end of the range is included or
+/// not.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RangeLimits {
+    /// A half-open range `start..end`, which doesn't include the end.
+    HalfOpen,
+    /// A closed range `start..=end`, which includes the end.
+    Closed,
+}
+
+impl fmt::Display for RangeLimits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::HalfOpen => write!(f, ".."),
+            Self::Closed => write!(f, "..="),
+        }
+    }
+}
+
+/// A range of integers, as constructed through `start..end` or
+/// `start..=end`.
+///
+/// Ranges where the start is after the end are considered empty.
+///
+/// # Examples
+///
+/// ```rust
+/// use runestick::{Range, RangeLimits};
+///
+/// let range = Range::new(0, 5, RangeLimits::HalfOpen);
+/// assert!(range.contains(4));
+/// assert!(!range.contains(5));
+///
+/// let range = Range::new(0, 5, RangeLimits::Closed);
+/// assert!(range.contains(5));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Range {
+    /// The start of the range.
+    pub start: i64,
+    /// The end of the range.
+    pub end: i64,
+    /// The limits of the range.
+    pub limits: RangeLimits,
+}
+
+impl Range {
+    /// Construct a new range.
+    pub fn new(start: i64, end: i64, limits: RangeLimits) -> Self {
+        Self { start, end, limits }
+    }
+
+    /// Test if the range contains the given number.
+    pub fn contains(&self, n: i64) -> bool {
+        match self.limits {
+            RangeLimits::HalfOpen => self.start <= n && n < self.end,
+            RangeLimits::Closed => self.start <= n && n <= self.end,
+        }
+    }
+
+    /// Test if the range is empty.
+    pub fn is_empty(&self) -> bool {
+        match self.limits {
+            RangeLimits::HalfOpen => self.start >= self.end,
+            RangeLimits::Closed => self.start > self.end,
+        }
+    }
+}
+
+impl fmt::Display for Range {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}{}", self.start, self.limits, self.end)
+    }
+}
+
+crate::__internal_impl_any!(Range);
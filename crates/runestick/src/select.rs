@@ -2,34 +2,58 @@ use crate::future::SelectFuture;
 use crate::{Future, Mut, Value, VmError};
 use futures_core::Stream;
 use futures_util::stream::FuturesUnordered;
+use std::fmt;
 use std::future;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 
+/// A timer future which completes once a select has timed out.
+pub(crate) type Timeout = Pin<Box<dyn future::Future<Output = ()>>>;
+
 /// A stored select.
-#[derive(Debug)]
 pub struct Select {
     futures: FuturesUnordered<SelectFuture<usize, Mut<Future>>>,
+    timeout: Option<Timeout>,
 }
 
 impl Select {
+    /// The branch which is selected if the select times out before any of
+    /// its futures complete.
+    pub const TIMEOUT_BRANCH: i64 = -1;
+
     /// Construct a new stored select.
-    pub(crate) fn new(futures: FuturesUnordered<SelectFuture<usize, Mut<Future>>>) -> Self {
-        Self { futures }
+    pub(crate) fn new(
+        futures: FuturesUnordered<SelectFuture<usize, Mut<Future>>>,
+        timeout: Option<Timeout>,
+    ) -> Self {
+        Self { futures, timeout }
+    }
+}
+
+impl fmt::Debug for Select {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Select")
+            .field("futures", &self.futures)
+            .field("has_timeout", &self.timeout.is_some())
+            .finish()
     }
 }
 
 impl future::Future for Select {
-    type Output = Result<(usize, Value), VmError>;
+    type Output = Result<(i64, Value), VmError>;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        let poll = Pin::new(&mut self.futures).poll_next(cx);
+        if let Poll::Ready(poll) = Pin::new(&mut self.futures).poll_next(cx) {
+            let (branch, value) = poll.expect("inner stream should never end")?;
+            return Poll::Ready(Ok((branch as i64, value)));
+        }
 
-        let poll = match poll {
-            Poll::Ready(poll) => poll.expect("inner stream should never end"),
-            Poll::Pending => return Poll::Pending,
-        };
+        if let Some(timeout) = &mut self.timeout {
+            if timeout.as_mut().poll(cx).is_ready() {
+                return Poll::Ready(Ok((Self::TIMEOUT_BRANCH, Value::Unit)));
+            }
+        }
 
-        Poll::Ready(poll)
+        Poll::Pending
     }
 }
@@ -26,6 +26,7 @@ impl<T> Shared<T> {
         let inner = Box::leak(Box::new(SharedBox {
             access: Access::new(false),
             count: Cell::new(1),
+            weak: Cell::new(0),
             data: data.into(),
         }));
 
@@ -355,6 +356,52 @@ impl<T: ?Sized> Shared<T> {
             Ok(BorrowMut::new(&mut *inner.data.get(), &inner.access))
         }
     }
+
+    /// Construct a weak reference to the shared value, which does not keep
+    /// the value alive.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::Shared;
+    ///
+    /// let a = Shared::new(1u32);
+    /// let weak = Shared::downgrade(&a);
+    /// assert!(weak.is_alive());
+    ///
+    /// drop(a);
+    /// assert!(!weak.is_alive());
+    /// assert!(weak.upgrade().is_none());
+    /// ```
+    pub fn downgrade(this: &Self) -> Weak<T> {
+        // Safety: the box is valid since this container is alive.
+        unsafe {
+            SharedBox::inc_weak(this.inner.as_ptr());
+        }
+
+        Weak { inner: this.inner }
+    }
+
+    /// Get the number of strong references to the shared value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::Shared;
+    ///
+    /// let a = Shared::new(1u32);
+    /// assert_eq!(Shared::strong_count(&a), 1);
+    ///
+    /// let b = a.clone();
+    /// assert_eq!(Shared::strong_count(&a), 2);
+    ///
+    /// drop(b);
+    /// assert_eq!(Shared::strong_count(&a), 1);
+    /// ```
+    pub fn strong_count(this: &Self) -> usize {
+        // Safety: the box is valid since this container is alive.
+        unsafe { (*this.inner.as_ptr()).count.get() }
+    }
 }
 
 impl Shared<AnyObj> {
@@ -440,6 +487,7 @@ impl Shared<AnyObj> {
         let inner = ptr::NonNull::from(Box::leak(Box::new(SharedBox {
             access: Access::new(true),
             count: Cell::new(2),
+            weak: Cell::new(0),
             data: any.into(),
         })));
 
@@ -691,6 +739,61 @@ where
     }
 }
 
+/// A weak reference to a shared value, constructed using
+/// [downgrade][Shared::downgrade].
+///
+/// This does not keep the shared value alive.
+pub struct Weak<T: ?Sized> {
+    inner: ptr::NonNull<SharedBox<T>>,
+}
+
+impl<T: ?Sized> Weak<T> {
+    /// Test if the shared value is still alive.
+    pub fn is_alive(&self) -> bool {
+        // Safety: the box is kept allocated by weak references.
+        unsafe { self.inner.as_ref().count.get() != 0 }
+    }
+
+    /// Try to upgrade the weak reference into a strong one, returning `None`
+    /// if the shared value is no longer alive.
+    pub fn upgrade(&self) -> Option<Shared<T>> {
+        if !self.is_alive() {
+            return None;
+        }
+
+        // Safety: the value is alive, so the strong count can be incremented.
+        unsafe {
+            SharedBox::inc(self.inner.as_ptr());
+        }
+
+        Some(Shared { inner: self.inner })
+    }
+}
+
+impl<T: ?Sized> Clone for Weak<T> {
+    fn clone(&self) -> Self {
+        unsafe {
+            SharedBox::inc_weak(self.inner.as_ptr());
+        }
+
+        Self { inner: self.inner }
+    }
+}
+
+impl<T: ?Sized> Drop for Weak<T> {
+    fn drop(&mut self) {
+        unsafe {
+            SharedBox::dec_weak(self.inner.as_ptr());
+        }
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for Weak<T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "(Weak)")
+    }
+}
+
 /// A debug helper that prints detailed diagnostics on the type being debugged.
 ///
 /// Constructed using [debug][Shared::debug].
@@ -711,6 +814,7 @@ where
 
             debug.field("access", &inner.access);
             debug.field("count", &inner.count.get());
+            debug.field("weak", &inner.weak.get());
 
             if !inner.access.is_shared() {
                 debug.field("data", &any::type_name::<T>());
@@ -730,6 +834,9 @@ struct SharedBox<T: ?Sized> {
     access: Access,
     /// The number of strong references to the shared data.
     count: Cell<usize>,
+    /// The number of weak references to the shared data. The box itself is
+    /// only freed once both this and `count` have reached zero.
+    weak: Cell<usize>,
     /// The value being held. Guarded by the `access` field to determine if it
     /// can be access shared or exclusively.
     data: UnsafeCell<T>,
@@ -768,13 +875,7 @@ impl<T: ?Sized> SharedBox<T> {
             return false;
         }
 
-        if (*this).access.is_taken() {
-            // NB: This prevents the inner `T` from being dropped in case it
-            // has already been taken (as indicated by `is_taken`).
-            //
-            // If it has been taken, the shared box contains invalid memory.
-            let _ = std::mem::transmute::<_, Box<SharedBox<ManuallyDrop<T>>>>(Box::from_raw(this));
-        } else {
+        if !(*this).access.is_taken() {
             // NB: At the point of the final drop, no on else should be using
             // this.
             debug_assert!(
@@ -782,11 +883,60 @@ impl<T: ?Sized> SharedBox<T> {
                 "expected exclusive, but was: {:?}",
                 (*this).access
             );
-            let _ = Box::from_raw(this);
+
+            // NB: If the value has been taken (as indicated by `is_taken`), the
+            // shared box contains invalid memory and must not be dropped.
+            ptr::drop_in_place((*this).data.get());
+        }
+
+        if (*this).weak.get() == 0 {
+            Self::free(this);
         }
 
         true
     }
+
+    /// Increment the weak reference count of the inner value.
+    unsafe fn inc_weak(this: *const Self) {
+        let weak = (*this).weak.get();
+
+        if weak == usize::max_value() {
+            process::abort();
+        }
+
+        (*this).weak.set(weak + 1);
+    }
+
+    /// Decrement the weak reference count in inner, and free the box if there
+    /// are no remaining references to it.
+    ///
+    /// # Safety
+    ///
+    /// Caller needs to ensure that `this` is a valid pointer.
+    unsafe fn dec_weak(this: *mut Self) {
+        let weak = (*this).weak.get();
+
+        if weak == 0 {
+            process::abort();
+        }
+
+        let weak = weak - 1;
+        (*this).weak.set(weak);
+
+        if weak == 0 && (*this).count.get() == 0 {
+            Self::free(this);
+        }
+    }
+
+    /// Free the memory of the box without dropping the data it contains.
+    ///
+    /// # Safety
+    ///
+    /// Caller needs to ensure that `this` is a valid pointer, and that the
+    /// data it contains has already been dropped or taken.
+    unsafe fn free(this: *mut Self) {
+        let _ = std::mem::transmute::<_, Box<SharedBox<ManuallyDrop<T>>>>(Box::from_raw(this));
+    }
 }
 
 type DropFn = unsafe fn(*const ());
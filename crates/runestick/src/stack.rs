@@ -18,6 +18,8 @@ pub struct Stack {
     ///
     /// It is not possible to interact with values below this stack frame.
     stack_bottom: usize,
+    /// The largest number of values which have been on the stack at once.
+    high_water_mark: usize,
 }
 
 impl Stack {
@@ -26,6 +28,7 @@ impl Stack {
         Self {
             stack: Vec::new(),
             stack_bottom: 0,
+            high_water_mark: 0,
         }
     }
 
@@ -35,6 +38,7 @@ impl Stack {
         I: IntoIterator<Item = Value>,
     {
         self.stack.extend(iter);
+        self.high_water_mark = usize::max(self.high_water_mark, self.stack.len());
     }
 
     /// Get the offset that corresponds to the top of the stack right now.
@@ -47,13 +51,34 @@ impl Stack {
         Self {
             stack: Vec::with_capacity(capacity),
             stack_bottom: 0,
+            high_water_mark: 0,
         }
     }
 
+    /// Get the number of values the stack can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.stack.capacity()
+    }
+
+    /// Reserve capacity for at least `additional` more values to be pushed
+    /// onto the stack without reallocating.
+    pub fn reserve(&mut self, additional: usize) {
+        self.stack.reserve(additional);
+    }
+
+    /// Get the largest number of values which have been on the stack at once.
+    ///
+    /// This can be used to determine what capacity to construct a stack with
+    /// to avoid reallocating it.
+    pub fn high_water_mark(&self) -> usize {
+        self.high_water_mark
+    }
+
     /// Clear the current stack.
     pub fn clear(&mut self) {
         self.stack.clear();
         self.stack_bottom = 0;
+        self.high_water_mark = 0;
     }
 
     /// Get the given slice of the stack, if it isn't out of range.
@@ -111,12 +136,33 @@ impl Stack {
         }
     }
 
+    /// Swap the values at the two given frame offsets.
+    pub fn swap(&mut self, a: usize, b: usize) -> Result<(), StackError> {
+        let len = self.stack.len();
+
+        let a = self
+            .stack_bottom
+            .checked_add(a)
+            .filter(|n| *n < len)
+            .ok_or_else(|| StackError(()))?;
+
+        let b = self
+            .stack_bottom
+            .checked_add(b)
+            .filter(|n| *n < len)
+            .ok_or_else(|| StackError(()))?;
+
+        self.stack.swap(a, b);
+        Ok(())
+    }
+
     /// Push a value onto the stack.
     pub fn push<T>(&mut self, value: T)
     where
         Value: From<T>,
     {
         self.stack.push(Value::from(value));
+        self.high_water_mark = usize::max(self.high_water_mark, self.stack.len());
     }
 
     /// Pop a reference to a value from the stack.
@@ -208,16 +254,15 @@ impl Stack {
 
 impl iter::FromIterator<Value> for Stack {
     fn from_iter<T: IntoIterator<Item = Value>>(iter: T) -> Self {
-        Self {
-            stack: iter.into_iter().collect(),
-            stack_bottom: 0,
-        }
+        let stack: Vec<Value> = iter.into_iter().collect();
+        Self::from(stack)
     }
 }
 
 impl From<Vec<Value>> for Stack {
     fn from(stack: Vec<Value>) -> Self {
         Self {
+            high_water_mark: stack.len(),
             stack,
             stack_bottom: 0,
         }
@@ -123,6 +123,7 @@ pub static TUPLE_TYPE: &StaticType = &StaticType {
 };
 
 impl_static_type!(crate::Tuple => TUPLE_TYPE);
+impl_static_type!(crate::Shared<crate::Tuple> => TUPLE_TYPE);
 impl_static_type!(crate::TupleVariant => TUPLE_TYPE);
 
 /// The specialized type information for an anonymous object type.
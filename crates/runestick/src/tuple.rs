@@ -1,4 +1,6 @@
-use crate::{ConstValue, FromValue, Mut, Ref, Value, VmError};
+use crate::{
+    ConstValue, FromValue, Mut, Named, RawRef, RawStr, Ref, Shared, UnsafeFromValue, Value, VmError,
+};
 use std::fmt;
 use std::ops;
 
@@ -10,7 +12,8 @@ pub struct Tuple {
 }
 
 impl Tuple {
-    fn empty() -> Self {
+    /// Construct a new empty tuple, without allocating.
+    pub fn empty() -> Self {
         Self {
             inner: Vec::new().into_boxed_slice(),
         }
@@ -118,6 +121,16 @@ impl From<Box<[ConstValue]>> for Tuple {
     }
 }
 
+impl Named for Tuple {
+    const NAME: RawStr = RawStr::from_str("Tuple");
+}
+
+impl FromValue for Shared<Tuple> {
+    fn from_value(value: Value) -> Result<Self, VmError> {
+        Ok(value.into_tuple()?)
+    }
+}
+
 impl FromValue for Mut<Tuple> {
     fn from_value(value: Value) -> Result<Self, VmError> {
         Ok(value.into_tuple()?.into_mut()?)
@@ -140,6 +153,20 @@ impl FromValue for Tuple {
     }
 }
 
+impl UnsafeFromValue for &Tuple {
+    type Output = *const Tuple;
+    type Guard = RawRef;
+
+    fn from_value(value: Value) -> Result<(Self::Output, Self::Guard), VmError> {
+        let tuple = value.into_tuple()?;
+        Ok(Ref::into_raw(tuple.into_ref()?))
+    }
+
+    unsafe fn unsafe_coerce(output: Self::Output) -> Self {
+        &*output
+    }
+}
+
 macro_rules! impl_tuple {
     () => ();
 
@@ -156,7 +183,15 @@ macro_rules! impl_tuple {
             $($ty: $crate::FromValue,)*
         {
             fn from_value(value: $crate::Value) -> Result<Self, $crate::VmError> {
-                let tuple = value.into_tuple()?.take()?;
+                let tuple = match value {
+                    $crate::Value::Tuple(tuple) => tuple.take()?,
+                    actual => {
+                        return Err($crate::VmError::from($crate::VmErrorKind::ExpectedTuple {
+                            actual: actual.type_info()?,
+                            expected: $count,
+                        }));
+                    }
+                };
 
                 if tuple.len() != $count {
                     return Err($crate::VmError::from($crate::VmErrorKind::ExpectedTupleLength {
@@ -170,7 +205,9 @@ macro_rules! impl_tuple {
 
                 $(
                     let $var = match it.next() {
-                        Some(value) => <$ty>::from_value(value)?,
+                        Some(value) => <$ty>::from_value(value).map_err(|e| {
+                            e.with_path($crate::ValuePathSegment::TupleField($count - $ignore_count))
+                        })?,
                         None => {
                             return Err($crate::VmError::from($crate::VmErrorKind::IterationError));
                         },
@@ -5,11 +5,48 @@
 
 use crate::collections::HashMap;
 use crate::{
-    Call, DebugInfo, Hash, Inst, Rtti, StaticString, Type, VariantRtti, VmError, VmErrorKind,
+    Call, DebugInfo, Hash, Inst, Rtti, Source, Span, StaticString, Type, VariantRtti, VmError,
+    VmErrorKind,
 };
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::sync::Arc;
+use thiserror::Error;
+
+/// Magic bytes at the start of a serialized unit.
+const MAGIC: [u8; 4] = *b"RUNE";
+
+/// The version of runestick used to serialize a unit.
+///
+/// The layout of instructions can change between any two versions, so a unit
+/// can only be loaded by the same version that serialized it.
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Error raised when serializing or deserializing a [Unit].
+#[derive(Debug, Error)]
+pub enum UnitFormatError {
+    /// The data is not a serialized unit.
+    #[error("not a serialized unit")]
+    BadMagic,
+    /// The unit was serialized by a different version of runestick.
+    #[error("unit was serialized by version `{actual}`, but expected version `{expected}`")]
+    IncompatibleVersion {
+        /// The version of the current runestick.
+        expected: &'static str,
+        /// The version the unit was serialized by.
+        actual: String,
+    },
+    /// The unit failed to encode or decode.
+    #[error("unit could not be encoded or decoded")]
+    Bincode(#[from] bincode::Error),
+}
+
+/// Header prefixed to a serialized unit.
+#[derive(Serialize, Deserialize)]
+struct UnitHeader<V> {
+    magic: [u8; 4],
+    version: V,
+}
 
 /// Instructions from a single source file.
 #[derive(Debug, Default, Serialize, Deserialize)]
@@ -66,6 +103,46 @@ impl Unit {
         }
     }
 
+    /// Serialize the unit into bytes, which can be loaded again using
+    /// [Unit::from_bytes].
+    ///
+    /// This includes the instructions of the unit, all of its lookaside tables,
+    /// and debug info if present.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, UnitFormatError> {
+        let header = UnitHeader {
+            magic: MAGIC,
+            version: VERSION,
+        };
+
+        let mut out = bincode::serialize(&header)?;
+        bincode::serialize_into(&mut out, self)?;
+        Ok(out)
+    }
+
+    /// Deserialize a unit from bytes produced by [Unit::to_bytes].
+    ///
+    /// This errors if the unit was serialized by a different version of
+    /// runestick.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, UnitFormatError> {
+        // NB: check the magic bytes up front, so that we never try to decode
+        // arbitrary data.
+        if !bytes.starts_with(&MAGIC) {
+            return Err(UnitFormatError::BadMagic);
+        }
+
+        let mut reader = bytes;
+        let header = bincode::deserialize_from::<_, UnitHeader<String>>(&mut reader)?;
+
+        if header.version != VERSION {
+            return Err(UnitFormatError::IncompatibleVersion {
+                expected: VERSION,
+                actual: header.version,
+            });
+        }
+
+        Ok(bincode::deserialize_from(reader)?)
+    }
+
     /// Access the type for the given language item.
     pub fn lookup_type(&self, hash: Hash) -> Option<&UnitTypeInfo> {
         self.types.get(&hash)
@@ -82,11 +159,51 @@ impl Unit {
         self.instructions.get(ip)
     }
 
+    /// Get the instructions of the function with the given hash, as they are
+    /// executed by the virtual machine.
+    ///
+    /// The first instruction is located at the `offset` of the function, as
+    /// returned by [lookup][Self::lookup], so jump targets and instruction
+    /// pointers are relative to the whole unit and not the returned slice.
+    pub fn function_instructions(&self, hash: Hash) -> Option<&[Inst]> {
+        let start = match self.functions.get(&hash)? {
+            UnitFn::Offset { offset, .. } => *offset,
+            _ => return None,
+        };
+
+        // NB: functions are laid out one after another, so a function ends
+        // where the next one starts.
+        let end = self
+            .functions
+            .values()
+            .filter_map(|f| match f {
+                UnitFn::Offset { offset, .. } if *offset > start => Some(*offset),
+                _ => None,
+            })
+            .min()
+            .unwrap_or_else(|| self.instructions.len());
+
+        self.instructions.get(start..end)
+    }
+
+    /// Get the source span of the instruction at the given instruction pointer.
+    ///
+    /// This requires the unit to have been compiled with debug info.
+    pub fn instruction_span(&self, ip: usize) -> Option<(usize, Span)> {
+        let inst = self.debug_info()?.instruction_at(ip)?;
+        Some((inst.source_id, inst.span))
+    }
+
     /// Iterate over all static strings in the unit.
     pub fn iter_static_strings(&self) -> impl Iterator<Item = &Arc<StaticString>> + '_ {
         self.static_strings.iter()
     }
 
+    /// Iterate over all static byte strings in the unit.
+    pub fn iter_static_bytes(&self) -> impl Iterator<Item = &[u8]> + '_ {
+        self.static_bytes.iter().map(|bytes| &bytes[..])
+    }
+
     /// Iterate over all static object keys in the unit.
     pub fn iter_static_object_keys(&self) -> impl Iterator<Item = (usize, &[String])> + '_ {
         let mut it = self.static_object_keys.iter().enumerate();
@@ -148,6 +265,155 @@ impl Unit {
     pub fn lookup(&self, hash: Hash) -> Option<UnitFn> {
         self.functions.get(&hash).copied()
     }
+
+    /// Disassemble the unit into a human readable listing.
+    ///
+    /// Every instruction is numbered and grouped under the function it belongs
+    /// to, and jumps are annotated with the absolute instruction they target.
+    /// The listing ends with the static string, byte string and object key
+    /// tables of the unit.
+    pub fn disassemble(&self) -> String {
+        let mut out = String::new();
+        // NB: writing to a string is infallible.
+        let _ = self.disassemble_to(&mut out, None);
+        out
+    }
+
+    /// Disassemble the unit like [Unit::disassemble], interleaving the lines of
+    /// source which produced each run of instructions.
+    ///
+    /// The `source_at` callback looks up the source for a source id recorded
+    /// in the debug info of the unit.
+    pub fn disassemble_with_source<'a, F>(&self, source_at: F) -> String
+    where
+        F: Fn(usize) -> Option<&'a Source>,
+    {
+        let mut out = String::new();
+        // NB: writing to a string is infallible.
+        let _ = self.disassemble_to(&mut out, Some(&source_at));
+        out
+    }
+
+    fn disassemble_to<'a>(
+        &self,
+        out: &mut String,
+        source_at: Option<&dyn Fn(usize) -> Option<&'a Source>>,
+    ) -> fmt::Result {
+        use std::fmt::Write as _;
+
+        let offsets = self
+            .functions
+            .iter()
+            .filter_map(|(hash, f)| match f {
+                UnitFn::Offset { offset, .. } => Some((*offset, *hash)),
+                _ => None,
+            })
+            .collect::<HashMap<_, _>>();
+
+        let debug = self.debug_info();
+        let mut last_line = None;
+
+        for (n, inst) in self.instructions.iter().enumerate() {
+            if let Some((hash, signature)) = debug.and_then(|d| d.function_at(n)) {
+                writeln!(out, "fn {} ({}):", signature, hash)?;
+            } else if let Some(hash) = offsets.get(&n) {
+                writeln!(out, "fn {}:", hash)?;
+            }
+
+            let debug = debug.and_then(|d| d.instruction_at(n));
+
+            if let (Some(source_at), Some(debug)) = (source_at, debug) {
+                if let Some(source) = source_at(debug.source_id) {
+                    let (line, text) = source_line(source, debug.span.start.into_usize());
+
+                    if last_line != Some((debug.source_id, line)) {
+                        writeln!(out, "{:>4} | {}", line + 1, text)?;
+                        last_line = Some((debug.source_id, line));
+                    }
+                }
+            }
+
+            if let Some(label) = debug.and_then(|d| d.label.as_ref()) {
+                writeln!(out, "{}:", label)?;
+            }
+
+            write!(out, "  {:04} = {}", n, inst)?;
+
+            if let Some(target) = jump_target(n, inst) {
+                write!(out, " // -> {:04}", target)?;
+            }
+
+            if let Some(comment) = debug.and_then(|d| d.comment.as_ref()) {
+                write!(out, " // {}", comment)?;
+            }
+
+            writeln!(out)?;
+        }
+
+        if !self.static_strings.is_empty() {
+            writeln!(out, "# strings")?;
+
+            for (slot, string) in self.static_strings.iter().enumerate() {
+                writeln!(out, "  {:04} = {:?}", slot, string.as_str())?;
+            }
+        }
+
+        if !self.static_bytes.is_empty() {
+            writeln!(out, "# bytes")?;
+
+            for (slot, bytes) in self.static_bytes.iter().enumerate() {
+                writeln!(out, "  {:04} = {:?}", slot, bytes)?;
+            }
+        }
+
+        if !self.static_object_keys.is_empty() {
+            writeln!(out, "# object keys")?;
+
+            for (slot, keys) in self.static_object_keys.iter().enumerate() {
+                writeln!(out, "  {:04} = {:?}", slot, keys)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Get the line containing the given byte offset, and its zero-based number.
+fn source_line(source: &Source, offset: usize) -> (usize, &str) {
+    let line_starts = source.line_starts();
+
+    let line = match line_starts.binary_search(&offset) {
+        Ok(n) => n,
+        Err(n) => n.saturating_sub(1),
+    };
+
+    let start = line_starts.get(line).copied().unwrap_or_default();
+
+    let text = match line_starts.get(line + 1) {
+        Some(end) => source.get(start..*end),
+        None => source.get(start..),
+    };
+
+    (line, text.unwrap_or_default().trim_end())
+}
+
+/// Resolve the absolute target of the jump instruction at `ip`, if it is one.
+fn jump_target(ip: usize, inst: &Inst) -> Option<usize> {
+    let offset = match *inst {
+        Inst::Jump { offset }
+        | Inst::JumpIf { offset }
+        | Inst::JumpIfNot { offset }
+        | Inst::JumpIfOrPop { offset }
+        | Inst::JumpIfNotOrPop { offset }
+        | Inst::JumpIfBranch { offset, .. }
+        | Inst::PopAndJumpIfNot { offset, .. } => offset,
+        _ => return None,
+    };
+
+    // NB: the instruction pointer is advanced past the jump after its offset
+    // has been applied.
+    let ip = ip as isize + 1;
+    Some(ip.wrapping_add(offset) as usize)
 }
 
 /// The kind and necessary information on registered functions.
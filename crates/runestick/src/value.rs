@@ -1,9 +1,11 @@
 use crate::access::AccessKind;
 use crate::{
-    Any, AnyObj, Bytes, Format, Function, Future, Generator, GeneratorState, Hash, Item, Mut,
-    Object, RawMut, RawRef, Ref, Shared, StaticString, Stream, Tuple, Type, TypeInfo, Vec, VmError,
+    AccessError, Any, AnyObj, BorrowMut, BorrowRef, Bytes, Format, Function, Future, Generator,
+    GeneratorState, Hash, Item, Mut, Object, RawMut, RawRef, Ref, Shared, StaticString, Stream,
+    Tuple, Type, TypeInfo, Vec, VmError, VmErrorKind,
 };
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::fmt;
 use std::sync::Arc;
 use std::vec;
@@ -660,6 +662,61 @@ impl Value {
         }
     }
 
+    /// Borrow the external value this holds as a reference to `T`.
+    ///
+    /// Returns `None` if the value isn't an external value of type `T`, and
+    /// errors if the value can't currently be borrowed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::{Any, ToValue as _};
+    ///
+    /// #[derive(Debug, PartialEq, Any)]
+    /// struct Thing(u32);
+    ///
+    /// #[derive(Debug, PartialEq, Any)]
+    /// struct Other;
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let value = Thing(42).to_value()?;
+    /// assert_eq!(Some(&Thing(42)), value.downcast_ref::<Thing>()?.as_deref());
+    /// assert_eq!(None, value.downcast_ref::<Other>()?.as_deref());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn downcast_ref<T>(&self) -> Result<Option<BorrowRef<'_, T>>, VmError>
+    where
+        T: Any,
+    {
+        match self {
+            Self::Any(any) => match any.downcast_borrow_ref::<T>() {
+                Ok(value) => Ok(Some(value)),
+                Err(AccessError::UnexpectedType { .. }) => Ok(None),
+                Err(error) => Err(VmError::from(error)),
+            },
+            _ => Ok(None),
+        }
+    }
+
+    /// Borrow the external value this holds as a mutable reference to `T`.
+    ///
+    /// Returns `None` if the value isn't an external value of type `T`, and
+    /// errors if the value can't currently be borrowed.
+    pub fn downcast_mut<T>(&self) -> Result<Option<BorrowMut<'_, T>>, VmError>
+    where
+        T: Any,
+    {
+        match self {
+            Self::Any(any) => match any.downcast_borrow_mut::<T>() {
+                Ok(value) => Ok(Some(value)),
+                Err(AccessError::UnexpectedType { .. }) => Ok(None),
+                Err(error) => Err(VmError::from(error)),
+            },
+            _ => Ok(None),
+        }
+    }
+
     /// Get the type information for the current value.
     pub fn type_of(&self) -> Result<Type, VmError> {
         Ok(match self {
@@ -732,9 +789,14 @@ impl Value {
     /// each other.
     ///
     /// This is the basis for the eq operation (`==`).
+    ///
+    /// Containers are always compared element by element, even when both sides
+    /// refer to the same container. This keeps floats consistent, since a
+    /// container holding `NaN` is not equal to itself.
     pub(crate) fn value_ptr_eq(a: &Value, b: &Value) -> Result<bool, VmError> {
         Ok(match (a, b) {
             (Self::Unit, Self::Unit) => true,
+            (Self::Byte(a), Self::Byte(b)) => a == b,
             (Self::Char(a), Self::Char(b)) => a == b,
             (Self::Bool(a), Self::Bool(b)) => a == b,
             (Self::Integer(a), Self::Integer(b)) => a == b,
@@ -797,6 +859,65 @@ impl Value {
             _ => false,
         })
     }
+
+    /// Compare two values, returning their ordering if they are comparable.
+    ///
+    /// This is the basis for sorting. Values of different types can't be
+    /// compared and result in an error, while floats which aren't comparable
+    /// (like `NaN`) result in `None`.
+    pub fn partial_cmp(a: &Value, b: &Value) -> Result<Option<Ordering>, VmError> {
+        Ok(match (a, b) {
+            (Self::Unit, Self::Unit) => Some(Ordering::Equal),
+            (Self::Byte(a), Self::Byte(b)) => a.partial_cmp(b),
+            (Self::Char(a), Self::Char(b)) => a.partial_cmp(b),
+            (Self::Bool(a), Self::Bool(b)) => a.partial_cmp(b),
+            (Self::Integer(a), Self::Integer(b)) => a.partial_cmp(b),
+            (Self::Float(a), Self::Float(b)) => a.partial_cmp(b),
+            (Self::Vec(a), Self::Vec(b)) => {
+                let a = a.borrow_ref()?;
+                let b = b.borrow_ref()?;
+                Self::partial_cmp_seq(&a, &b)?
+            }
+            (Self::Tuple(a), Self::Tuple(b)) => {
+                let a = a.borrow_ref()?;
+                let b = b.borrow_ref()?;
+                Self::partial_cmp_seq(&a, &b)?
+            }
+            (Self::String(a), Self::String(b)) => {
+                let a = a.borrow_ref()?;
+                let b = b.borrow_ref()?;
+                a.as_str().partial_cmp(b.as_str())
+            }
+            (Self::StaticString(a), Self::String(b)) => {
+                let b = b.borrow_ref()?;
+                a.as_str().partial_cmp(b.as_str())
+            }
+            (Self::String(a), Self::StaticString(b)) => {
+                let a = a.borrow_ref()?;
+                a.as_str().partial_cmp(b.as_str())
+            }
+            (Self::StaticString(a), Self::StaticString(b)) => a.as_str().partial_cmp(b.as_str()),
+            (a, b) => {
+                return Err(VmError::from(VmErrorKind::UnsupportedBinaryOperation {
+                    op: "partial_cmp",
+                    lhs: a.type_info()?,
+                    rhs: b.type_info()?,
+                }))
+            }
+        })
+    }
+
+    /// Lexicographically compare two sequences of values.
+    fn partial_cmp_seq(a: &[Value], b: &[Value]) -> Result<Option<Ordering>, VmError> {
+        for (a, b) in a.iter().zip(b.iter()) {
+            match Self::partial_cmp(a, b)? {
+                Some(Ordering::Equal) => (),
+                ordering => return Ok(ordering),
+            }
+        }
+
+        Ok(a.len().partial_cmp(&b.len()))
+    }
 }
 
 impl fmt::Debug for Value {
@@ -1,8 +1,9 @@
 use crate::{
     FromValue, Mut, Named, RawMut, RawRef, RawStr, Ref, Shared, ToValue, UnsafeFromValue, Value,
-    VmError,
+    ValuePathSegment, VmError,
 };
 use std::fmt;
+use std::iter;
 use std::ops;
 use std::slice;
 use std::vec;
@@ -167,6 +168,14 @@ impl<'a> IntoIterator for &'a mut Vec {
     }
 }
 
+impl iter::FromIterator<Value> for Vec {
+    fn from_iter<T: IntoIterator<Item = Value>>(iter: T) -> Self {
+        Self {
+            inner: iter.into_iter().collect(),
+        }
+    }
+}
+
 impl From<vec::Vec<Value>> for Vec {
     fn from(inner: vec::Vec<Value>) -> Self {
         Self { inner }
@@ -209,8 +218,10 @@ where
 
         let mut output = vec::Vec::with_capacity(vec.len());
 
-        for value in vec {
-            output.push(T::from_value(value)?);
+        for (index, value) in vec.into_iter().enumerate() {
+            let value =
+                T::from_value(value).map_err(|e| e.with_path(ValuePathSegment::Index(index)))?;
+            output.push(value);
         }
 
         Ok(output)
@@ -1,4 +1,4 @@
-use crate::{FromValue, ToValue, Value, VmError, VmErrorKind};
+use crate::{FromValue, ToValue, Value, ValuePathSegment, VmError, VmErrorKind};
 
 /// A helper type to deserialize arrays with different interior types.
 ///
@@ -49,7 +49,9 @@ macro_rules! impl_from_value_tuple_vec {
 
                 $(
                     let $value: $ty = match it.next() {
-                        Some(value) => <$ty>::from_value(value)?,
+                        Some(value) => <$ty>::from_value(value).map_err(|e| {
+                            e.with_path(ValuePathSegment::Index($count - $ignore_count))
+                        })?,
                         None => {
                             return Err(VmError::from(VmErrorKind::IterationError));
                         },
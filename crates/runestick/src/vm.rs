@@ -1,16 +1,23 @@
 use crate::budget;
 use crate::future::SelectFuture;
+use crate::select::Timeout;
 use crate::unit::UnitFn;
 use crate::{
-    Args, Awaited, BorrowMut, Bytes, Call, Context, Format, FormatSpec, FromValue, Function,
-    Future, Generator, GuardedArgs, Hash, Inst, InstAssignOp, InstFnNameHash, InstOp, InstTarget,
-    IntoTypeHash, Object, Panic, Select, Shared, Stack, Stream, Struct, StructVariant, Tuple,
-    TypeCheck, Unit, UnitStruct, UnitVariant, Value, Vec, VmError, VmErrorKind, VmExecution,
-    VmHalt, VmIntegerRepr,
+    Args, Awaited, BorrowMut, Bytes, Call, CancellationToken, Context, Format, FormatSpec,
+    FromValue, Function, Future, Generator, GeneratorIter, GuardedArgs, Hash, Inst, InstAssignOp,
+    InstFnNameHash, InstOp, InstTarget, IntoTypeHash, Object, Panic, Range, RangeLimits, Select,
+    Shared, Span, Stack, Stream, Struct, StructVariant, ToValue, Tuple, TypeCheck, TypeInfo, Unit,
+    UnitStruct, UnitVariant, Value, Vec, VmError, VmErrorKind, VmExecution, VmHalt, VmIntegerRepr,
+    Weak,
 };
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::fmt;
+use std::future;
 use std::mem;
+use std::rc::Rc;
 use std::sync::Arc;
+use std::time::Duration;
 use std::vec;
 
 macro_rules! target_value {
@@ -50,6 +57,10 @@ macro_rules! target_value {
     }};
 }
 
+/// The default maximum number of nested call frames, see
+/// [Vm::with_max_call_depth].
+const DEFAULT_MAX_CALL_DEPTH: usize = 10_000;
+
 /// A stack which references variables indirectly from a slab.
 #[derive(Debug, Clone)]
 pub struct Vm {
@@ -63,6 +74,22 @@ pub struct Vm {
     stack: Stack,
     /// Frames relative to the stack.
     call_frames: vec::Vec<CallFrame>,
+    /// The maximum number of nested call frames.
+    max_call_depth: usize,
+    /// Token used to cancel the execution of the virtual machine.
+    cancellation: Option<CancellationToken>,
+    /// The maximum number of instructions to execute, if any.
+    instruction_limit: Option<usize>,
+    /// The number of instructions executed so far.
+    instructions: usize,
+    /// Hook called before each instruction is executed.
+    trace_hook: Option<TraceHook>,
+    /// Allocation sites of values, recorded if allocation tracking is enabled.
+    allocations: Option<Allocations>,
+    /// Timer used to time out select expressions.
+    timer: Option<Timer>,
+    /// Counts of executed instructions, recorded if profiling is enabled.
+    profile: Option<Profile>,
 }
 
 impl Vm {
@@ -79,9 +106,135 @@ impl Vm {
             ip: 0,
             stack,
             call_frames: vec::Vec::new(),
+            max_call_depth: DEFAULT_MAX_CALL_DEPTH,
+            cancellation: None,
+            instruction_limit: None,
+            instructions: 0,
+            trace_hook: None,
+            allocations: None,
+            timer: None,
+            profile: None,
+        }
+    }
+
+    /// Set a hook which is called before each instruction is executed.
+    ///
+    /// The hook receives the current instruction pointer, the instruction
+    /// about to be executed, and the values on the stack of the current call
+    /// frame.
+    pub fn set_trace_hook<F>(&mut self, hook: F)
+    where
+        F: 'static + FnMut(usize, &Inst, &[Value]),
+    {
+        self.trace_hook = Some(TraceHook(Rc::new(RefCell::new(hook))));
+    }
+
+    /// Clear the trace hook of the virtual machine, if one is set.
+    pub fn clear_trace_hook(&mut self) {
+        self.trace_hook = None;
+    }
+
+    /// Set the timer used to time out select expressions.
+    ///
+    /// The timer is called with the duration of the timeout and should return
+    /// a future which completes once that duration has passed, like
+    /// `tokio::time::delay_for`. The virtual machine doesn't have a notion of
+    /// time of its own, so a select with a timeout errors unless a timer is
+    /// set.
+    pub fn set_timer<F, T>(&mut self, timer: F)
+    where
+        F: 'static + Fn(Duration) -> T,
+        T: 'static + future::Future<Output = ()>,
+    {
+        self.timer = Some(Timer(Rc::new(move |duration| {
+            Box::pin(timer(duration)) as Timeout
+        })));
+    }
+
+    /// Enable tracking of the instructions which allocate values.
+    ///
+    /// This is intended for finding leaks. Allocations which are still alive
+    /// can be reported with [live_slots][Vm::live_slots]. Tracking is disabled
+    /// by default, in which case it costs nothing beyond a single check per
+    /// instruction.
+    pub fn track_allocations(&mut self) {
+        if self.allocations.is_none() {
+            self.allocations = Some(Allocations::default());
+        }
+    }
+
+    /// Report every value allocated while allocation tracking was enabled
+    /// which is still alive, in the order in which they were allocated.
+    ///
+    /// Returns an empty report if allocation tracking is not enabled.
+    pub fn live_slots(&self) -> vec::Vec<LiveSlot> {
+        let allocations = match &self.allocations {
+            Some(Allocations(allocations)) => allocations,
+            None => return vec::Vec::new(),
+        };
+
+        let mut allocations = allocations.borrow_mut();
+        allocations.retain(|(_, weak)| weak.is_alive());
+        allocations.iter().map(|(slot, _)| slot.clone()).collect()
+    }
+
+    /// Enable profiling of executed instructions.
+    ///
+    /// Every executed instruction is counted by its [name][Inst::name], and
+    /// the counts can be retrieved with [take_profile][Vm::take_profile].
+    /// Profiling is disabled by default, in which case it costs nothing beyond
+    /// a single check per instruction.
+    pub fn enable_profiling(&mut self) {
+        if self.profile.is_none() {
+            self.profile = Some(Profile::default());
         }
     }
 
+    /// Take the number of times each instruction has been executed since
+    /// profiling was enabled, or since the profile was last taken.
+    ///
+    /// Returns an empty profile if profiling is not enabled.
+    pub fn take_profile(&mut self) -> HashMap<&'static str, usize> {
+        match &self.profile {
+            Some(Profile(profile)) => mem::take(&mut *profile.borrow_mut()),
+            None => HashMap::new(),
+        }
+    }
+
+    /// Limit the number of instructions this virtual machine is allowed to
+    /// execute.
+    ///
+    /// Once the limit is reached, execution errors with
+    /// [VmErrorKind::BudgetExceeded]. By default there is no limit.
+    pub fn with_instruction_limit(mut self, limit: usize) -> Self {
+        self.instruction_limit = Some(limit);
+        self
+    }
+
+    /// Limit the number of nested calls this virtual machine is allowed to
+    /// make.
+    ///
+    /// Once the limit is reached, execution errors with
+    /// [VmErrorKind::StackOverflow] instead of growing the stack without
+    /// bound. The default limit is 10 000 nested calls.
+    pub fn with_max_call_depth(mut self, depth: usize) -> Self {
+        self.max_call_depth = depth;
+        self
+    }
+
+    /// Reserve capacity for at least `capacity` values on the stack of the
+    /// virtual machine.
+    ///
+    /// Programs with deep recursion or large call frames otherwise repeatedly
+    /// grow the stack while running. The
+    /// [high-water mark][Stack::high_water_mark] of a previous run can be
+    /// used to determine a suitable capacity.
+    pub fn with_stack_capacity(mut self, capacity: usize) -> Self {
+        self.stack
+            .reserve(capacity.saturating_sub(self.stack.len()));
+        self
+    }
+
     /// Run the given vm to completion.
     ///
     /// If any async instructions are encountered, this will error.
@@ -91,6 +244,11 @@ impl Vm {
     }
 
     /// Run the given vm to completion with support for async functions.
+    ///
+    /// The virtual machine doesn't have an executor of its own. Any future
+    /// awaited by a script is polled by the executor driving the returned
+    /// future, so the reactor it provides (like the one in tokio) is the one
+    /// used for timers and I/O.
     pub async fn async_complete(self) -> Result<Value, VmError> {
         let mut execution = VmExecution::new(self);
         execution.async_complete().await
@@ -135,11 +293,137 @@ impl Vm {
         &self.unit
     }
 
+    /// Get a token which can be used to cancel the execution of this virtual
+    /// machine.
+    ///
+    /// The token can be sent to another thread, where calling
+    /// [CancellationToken::cancel] causes the virtual machine to stop before
+    /// its next instruction with [VmErrorKind::Cancelled]. Cancellation is
+    /// independent of any [budget] the virtual machine is running under.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use runestick::{Context, Unit};
+    /// use std::sync::Arc;
+    /// use std::thread;
+    /// use std::time::Duration;
+    ///
+    /// fn main() -> runestick::Result<()> {
+    ///     let context = Arc::new(Context::with_default_modules()?);
+    ///     let unit = Arc::new(Unit::default());
+    ///
+    ///     let mut vm = runestick::Vm::new(context, unit);
+    ///     let token = vm.cancellation_token();
+    ///
+    ///     thread::spawn(move || {
+    ///         thread::sleep(Duration::from_secs(1));
+    ///         token.cancel();
+    ///     });
+    ///
+    ///     // NB: errors if the script doesn't finish within a second.
+    ///     let output = vm.execute(&["main"], ())?.complete();
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn cancellation_token(&mut self) -> CancellationToken {
+        self.cancellation
+            .get_or_insert_with(CancellationToken::new)
+            .clone()
+    }
+
+    /// Cancel the execution of this virtual machine.
+    ///
+    /// To cancel the virtual machine while it is running, use a token from
+    /// [Vm::cancellation_token] instead.
+    pub fn cancel(&mut self) {
+        self.cancellation_token().cancel();
+    }
+
+    /// Share the cancellation token of `other`, if it has one.
+    pub(crate) fn inherit_cancellation(&mut self, other: &Vm) {
+        if let Some(cancellation) = &other.cancellation {
+            self.cancellation = Some(cancellation.clone());
+        }
+    }
+
+    /// Share the trace hook of `other`, if it has one.
+    pub(crate) fn inherit_trace_hook(&mut self, other: &Vm) {
+        if let Some(hook) = &other.trace_hook {
+            self.trace_hook = Some(hook.clone());
+        }
+    }
+
+    /// Share the allocation tracking of `other`, if it is enabled.
+    pub(crate) fn inherit_allocations(&mut self, other: &Vm) {
+        if let Some(allocations) = &other.allocations {
+            self.allocations = Some(allocations.clone());
+        }
+    }
+
+    /// Share the profile of `other`, if profiling is enabled.
+    pub(crate) fn inherit_profile(&mut self, other: &Vm) {
+        if let Some(profile) = &other.profile {
+            self.profile = Some(profile.clone());
+        }
+    }
+
+    /// Share the timer of `other`, if it has one.
+    pub(crate) fn inherit_timer(&mut self, other: &Vm) {
+        if let Some(timer) = &other.timer {
+            self.timer = Some(timer.clone());
+        }
+    }
+
+    /// Record the value on top of the stack as allocated by the instruction
+    /// at the current instruction pointer.
+    fn record_allocation(&self) -> Result<(), VmError> {
+        let Allocations(allocations) = match &self.allocations {
+            Some(allocations) => allocations,
+            None => return Ok(()),
+        };
+
+        let value = self.stack.last()?;
+
+        let weak: Box<dyn IsAlive> = match value {
+            Value::String(value) => Box::new(Shared::downgrade(value)),
+            Value::Bytes(value) => Box::new(Shared::downgrade(value)),
+            Value::Vec(value) => Box::new(Shared::downgrade(value)),
+            Value::Tuple(value) => Box::new(Shared::downgrade(value)),
+            Value::Object(value) => Box::new(Shared::downgrade(value)),
+            Value::UnitStruct(value) => Box::new(Shared::downgrade(value)),
+            Value::TupleStruct(value) => Box::new(Shared::downgrade(value)),
+            Value::Struct(value) => Box::new(Shared::downgrade(value)),
+            Value::UnitVariant(value) => Box::new(Shared::downgrade(value)),
+            Value::TupleVariant(value) => Box::new(Shared::downgrade(value)),
+            Value::StructVariant(value) => Box::new(Shared::downgrade(value)),
+            Value::Function(value) => Box::new(Shared::downgrade(value)),
+            Value::Any(value) => Box::new(Shared::downgrade(value)),
+            _ => return Ok(()),
+        };
+
+        let debug = self
+            .unit
+            .debug_info()
+            .and_then(|debug| debug.instruction_at(self.ip));
+
+        let slot = LiveSlot {
+            ip: self.ip,
+            source_id: debug.map(|debug| debug.source_id),
+            span: debug.map(|debug| debug.span),
+            type_info: value.type_info()?,
+        };
+
+        allocations.borrow_mut().push((slot, weak));
+        Ok(())
+    }
+
     /// Reset this virtual machine, freeing all memory used.
     pub fn clear(&mut self) {
         self.ip = 0;
         self.stack.clear();
         self.call_frames.clear();
+        self.instructions = 0;
     }
 
     /// Access the current instruction pointer.
@@ -258,7 +542,7 @@ impl Vm {
         // Safety: We hold onto the guard until the vm has completed.
         let guard = unsafe { args.unsafe_into_stack(&mut self.stack)? };
 
-        let value = VmExecution::new(self).complete()?;
+        let value = VmExecution::new(self).async_complete().await?;
 
         // Note: this might panic if something in the vm is holding on to a
         // reference of the value. We should prevent it from being possible to
@@ -267,6 +551,87 @@ impl Vm {
         Ok(value)
     }
 
+    /// Call the given function, returning a future which drives the virtual
+    /// machine to completion on the executor which polls it.
+    ///
+    /// Unlike [async_call][Vm::async_call], the arguments are moved into the
+    /// virtual machine before the future is constructed, so the returned
+    /// future doesn't borrow from the caller and can be handed off to an
+    /// executor.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use runestick::{Context, FromValue, Unit};
+    /// use std::sync::Arc;
+    ///
+    /// async fn run() -> runestick::Result<()> {
+    ///     let context = Arc::new(Context::with_default_modules()?);
+    ///     let unit = Arc::new(Unit::default());
+    ///     // NB: normally the unit would be created by compiling some source,
+    ///     // and since this one is empty it won't do anything.
+    ///
+    ///     let vm = runestick::Vm::new(context, unit);
+    ///
+    ///     let output = vm.call_async(&["main"], (33i64,)).await?;
+    ///     let output = i64::from_value(output)?;
+    ///
+    ///     println!("output: {}", output);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn call_async<A, N>(
+        self,
+        name: N,
+        args: A,
+    ) -> impl future::Future<Output = Result<Value, VmError>>
+    where
+        N: IntoTypeHash,
+        A: Args,
+    {
+        let execution = self.execute(name, args);
+        async move { execution?.async_complete().await }
+    }
+
+    /// Call the given generator function, returning an iterator which resumes
+    /// the generator on demand and converts each yielded value into `T`.
+    ///
+    /// Errors raised while resuming the generator, or while converting a
+    /// yielded value, are produced as items of the iterator. Calling a function
+    /// which is not a generator produces no values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use runestick::{Context, Unit};
+    /// use std::sync::Arc;
+    ///
+    /// fn main() -> runestick::Result<()> {
+    ///     let context = Arc::new(Context::with_default_modules()?);
+    ///     let unit = Arc::new(Unit::default());
+    ///     // NB: normally the unit would be created by compiling some source,
+    ///     // and since this one is empty it won't do anything.
+    ///
+    ///     let vm = runestick::Vm::new(context, unit);
+    ///
+    ///     for value in vm.call_generator::<i64, _, _>(&["numbers"], ())? {
+    ///         println!("value: {}", value?);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn call_generator<T, A, N>(mut self, name: N, args: A) -> Result<GeneratorIter<T>, VmError>
+    where
+        T: FromValue,
+        N: IntoTypeHash,
+        A: Args,
+    {
+        self.set_entrypoint(name, args.count())?;
+        args.into_stack(&mut self.stack)?;
+        Ok(GeneratorIter::new(Generator::new(self)))
+    }
+
     // Update the instruction pointer to match the function matching the given
     // name and check that the number of argument matches.
     fn set_entrypoint<N>(&mut self, name: N, count: usize) -> Result<(), VmError>
@@ -290,7 +655,7 @@ impl Vm {
                 args: expected,
                 ..
             } => {
-                Self::check_args(count, expected)?;
+                self.check_fn_args(hash, count, expected)?;
                 offset
             }
             _ => {
@@ -314,9 +679,15 @@ impl Vm {
         }
     }
 
-    fn op_select(&mut self, len: usize) -> Result<Option<Select>, VmError> {
+    fn op_select(&mut self, len: usize, timeout: bool) -> Result<Option<Select>, VmError> {
         let futures = futures_util::stream::FuturesUnordered::new();
 
+        let duration = if timeout {
+            Some(Duration::from_value(self.stack.pop()?)?)
+        } else {
+            None
+        };
+
         let arguments = self.stack.drain_stack_top(len)?.collect::<vec::Vec<_>>();
 
         for (branch, value) in arguments.into_iter().enumerate() {
@@ -340,7 +711,19 @@ impl Vm {
             return Ok(None);
         }
 
-        Ok(Some(Select::new(futures)))
+        let timeout = match duration {
+            Some(duration) => {
+                let Timer(timer) = self
+                    .timer
+                    .as_ref()
+                    .ok_or_else(|| VmError::from(VmErrorKind::MissingTimer))?;
+
+                Some(timer(duration))
+            }
+            None => None,
+        };
+
+        Ok(Some(Select::new(futures, timeout)))
     }
 
     /// Helper function to call an instance function.
@@ -358,7 +741,7 @@ impl Vm {
             args: expected,
         }) = self.unit.lookup(hash)
         {
-            Self::check_args(count, expected)?;
+            self.check_fn_args(hash, count, expected)?;
             self.stack.push(target.clone());
             args.into_stack(&mut self.stack)?;
             self.call_offset_fn(offset, call, count)?;
@@ -442,7 +825,24 @@ impl Vm {
 
     #[inline]
     fn op_drop(&mut self, offset: usize) -> Result<(), VmError> {
-        let _ = self.stack.at_offset(offset)?;
+        let value = mem::replace(self.stack.at_offset_mut(offset)?, Value::Unit);
+
+        // NB: only the last reference to an external value drops it.
+        match &value {
+            Value::Any(any) if Shared::strong_count(any) == 1 => (),
+            _ => return Ok(()),
+        }
+
+        let hash = Hash::instance_function(value.type_of()?, crate::DROP.hash);
+
+        let handler = match self.context.lookup(hash) {
+            Some(handler) => handler.clone(),
+            None => return Ok(()),
+        };
+
+        self.stack.push(value);
+        handler(&mut self.stack, 1)?;
+        self.stack.pop()?;
         Ok(())
     }
 
@@ -492,6 +892,12 @@ impl Vm {
     /// This will cause the `args` number of elements on the stack to be
     /// associated and accessible to the new call frame.
     pub(crate) fn push_call_frame(&mut self, ip: usize, args: usize) -> Result<(), VmError> {
+        if self.call_frames.len() >= self.max_call_depth {
+            return Err(VmError::from(VmErrorKind::StackOverflow {
+                depth: self.max_call_depth,
+            }));
+        }
+
         let stack_top = self.stack.swap_stack_bottom(args)?;
 
         self.call_frames.push(CallFrame {
@@ -587,6 +993,11 @@ impl Vm {
     /// Construct a new vec.
     #[inline]
     fn op_vec(&mut self, count: usize) -> Result<(), VmError> {
+        if count == 0 {
+            self.stack.push(Shared::new(Vec::new()));
+            return Ok(());
+        }
+
         let vec = Vec::from(self.stack.pop_sequence(count)?);
         self.stack.push(Shared::new(vec));
         Ok(())
@@ -595,11 +1006,40 @@ impl Vm {
     /// Construct a new tuple.
     #[inline]
     fn op_tuple(&mut self, count: usize) -> Result<(), VmError> {
+        if count == 0 {
+            self.stack.push(Tuple::empty());
+            return Ok(());
+        }
+
         let tuple = self.stack.pop_sequence(count)?;
         self.stack.push(Tuple::from(tuple));
         Ok(())
     }
 
+    /// Construct a new range.
+    #[inline]
+    fn op_range(&mut self, limits: RangeLimits) -> Result<(), VmError> {
+        let end = self.stack.pop()?;
+        let start = self.stack.pop()?;
+
+        let range = match (start, end) {
+            (Value::Integer(start), Value::Integer(end)) => Range::new(start, end, limits),
+            (start, end) => {
+                return Err(VmError::from(VmErrorKind::UnsupportedBinaryOperation {
+                    op: match limits {
+                        RangeLimits::HalfOpen => "..",
+                        RangeLimits::Closed => "..=",
+                    },
+                    lhs: start.type_info()?,
+                    rhs: end.type_info()?,
+                }));
+            }
+        };
+
+        self.stack.push(range.to_value()?);
+        Ok(())
+    }
+
     /// Push the tuple that is on top of the stack.
     #[inline]
     fn op_push_tuple(&mut self) -> Result<(), VmError> {
@@ -634,7 +1074,10 @@ impl Vm {
 
         let value = match value {
             Value::Float(value) => Value::from(-value),
-            Value::Integer(value) => Value::from(-value),
+            Value::Integer(value) => match value.checked_neg() {
+                Some(value) => Value::from(value),
+                None => return Err(VmError::from(VmErrorKind::Overflow)),
+            },
             other => {
                 let operand = other.type_info()?;
                 return Err(VmError::from(VmErrorKind::UnsupportedUnaryOperation {
@@ -698,6 +1141,15 @@ impl Vm {
                     "%",
                 )?;
             }
+            InstOp::Pow => {
+                self.internal_num(
+                    crate::POW,
+                    || VmErrorKind::Overflow,
+                    |a, b| a.checked_pow(u32::try_from(b).ok()?),
+                    f64::powf,
+                    "**",
+                )?;
+            }
             InstOp::BitAnd => {
                 self.internal_infallible_bitwise(crate::BIT_AND, std::ops::BitAnd::bitand, "&")?;
             }
@@ -716,7 +1168,12 @@ impl Vm {
                 )?;
             }
             InstOp::Shr => {
-                self.internal_infallible_bitwise(crate::SHR, std::ops::Shr::shr, ">>")?;
+                self.internal_bitwise(
+                    crate::SHR,
+                    || VmErrorKind::Overflow,
+                    |a, b| a.checked_shr(u32::try_from(b).ok()?),
+                    ">>",
+                )?;
             }
             InstOp::Gt => {
                 self.internal_boolean_ops(|a, b| a > b, |a, b| a > b, ">")?;
@@ -754,6 +1211,9 @@ impl Vm {
                 let is_instance = self.is_instance()?;
                 self.stack.push(!is_instance);
             }
+            InstOp::In => {
+                self.internal_contains()?;
+            }
         }
 
         Ok(())
@@ -848,10 +1308,11 @@ impl Vm {
                 )?;
             }
             InstAssignOp::Shr => {
-                self.internal_infallible_bitwise_assign(
+                self.internal_bitwise_assign(
                     target,
                     crate::SHR_ASSIGN,
-                    std::ops::ShrAssign::shr_assign,
+                    || VmErrorKind::Overflow,
+                    |a, b| a.checked_shr(u32::try_from(b).ok()?),
                     ">>=",
                 )?;
             }
@@ -867,6 +1328,24 @@ impl Vm {
         let target = self.stack.pop()?;
         let value = self.stack.pop()?;
 
+        // NB: strings are immutable through indexing, since an index refers
+        // to a byte offset which might not be a character boundary.
+        if let Value::String(..) | Value::StaticString(..) = &target {
+            return Err(VmError::from(VmErrorKind::NotIndexAssignable {
+                type_info: target.type_info()?,
+            }));
+        }
+
+        if let Value::Integer(index) = &index {
+            use std::convert::TryFrom as _;
+
+            if let Ok(index) = usize::try_from(*index) {
+                if Self::try_tuple_like_index_set(&target, index, value.clone())? {
+                    return Ok(());
+                }
+            }
+        }
+
         // This is a useful pattern.
         #[allow(clippy::never_loop)]
         loop {
@@ -926,6 +1405,8 @@ impl Vm {
             }));
         }
 
+        // NB: discard the unit returned by the protocol function.
+        self.stack.pop()?;
         Ok(())
     }
 
@@ -1054,6 +1535,45 @@ impl Vm {
         Ok(Some(value))
     }
 
+    /// Implementation of slicing a vector-like value using a range.
+    fn try_slice_index_get(target: &Value, range: Range) -> Result<Option<Value>, VmError> {
+        use std::convert::TryFrom as _;
+
+        let vec = match target {
+            Value::Vec(vec) => vec.borrow_ref()?,
+            _ => return Ok(None),
+        };
+
+        let end = match range.limits {
+            RangeLimits::HalfOpen => Some(range.end),
+            RangeLimits::Closed => range.end.checked_add(1),
+        };
+
+        let (start, end) = match (
+            usize::try_from(range.start),
+            end.and_then(|end| usize::try_from(end).ok()),
+        ) {
+            (Ok(start), Some(end)) if end <= vec.len() => (start, end),
+            _ => {
+                return Err(VmError::from(VmErrorKind::SliceOutOfBounds {
+                    target: target.type_info()?,
+                    range,
+                    len: vec.len(),
+                }));
+            }
+        };
+
+        if start > end {
+            return Err(VmError::from(VmErrorKind::SliceReversed {
+                target: target.type_info()?,
+                range,
+            }));
+        }
+
+        let slice = Vec::from(vec[start..end].to_vec());
+        Ok(Some(Value::from(Shared::new(slice))))
+    }
+
     /// Implementation of getting a mutable value out of a tuple-like value.
     fn try_tuple_like_index_get_mut(
         target: &Value,
@@ -1273,8 +1793,21 @@ impl Vm {
                         return Ok(());
                     }
                 }
+                Value::Any(any) => {
+                    let range = match any.downcast_borrow_ref::<Range>() {
+                        Ok(range) => *range,
+                        Err(..) => break,
+                    };
+
+                    if let Some(value) = Self::try_slice_index_get(&target, range)? {
+                        self.stack.push(value);
+                        return Ok(());
+                    }
+                }
                 _ => break,
             };
+
+            break;
         }
 
         if !self.call_instance_fn(&target, crate::INDEX_GET, (&index,))? {
@@ -1480,6 +2013,46 @@ impl Vm {
         Ok(())
     }
 
+    /// Operation to copy the base of an object spread.
+    #[inline]
+    fn op_spread(&mut self, hash: Hash) -> Result<(), VmError> {
+        let base = self.stack.pop()?;
+
+        let value = match &base {
+            Value::Object(object) if hash == crate::OBJECT_TYPE.hash => {
+                Value::from(Shared::new(object.borrow_ref()?.clone()))
+            }
+            Value::Struct(object) if object.borrow_ref()?.rtti.hash == hash => {
+                let object = object.borrow_ref()?;
+
+                Value::from(Struct {
+                    rtti: object.rtti.clone(),
+                    data: object.data.clone(),
+                })
+            }
+            _ => {
+                let target = if hash == crate::OBJECT_TYPE.hash {
+                    TypeInfo::StaticType(crate::OBJECT_TYPE)
+                } else {
+                    let rtti = self
+                        .unit
+                        .lookup_rtti(hash)
+                        .ok_or_else(|| VmErrorKind::MissingRtti { hash })?;
+
+                    TypeInfo::Typed(rtti.clone())
+                };
+
+                return Err(VmError::from(VmErrorKind::UnsupportedSpread {
+                    target,
+                    actual: base.type_info()?,
+                }));
+            }
+        };
+
+        self.stack.push(value);
+        Ok(())
+    }
+
     /// Operation to allocate an object.
     #[inline]
     fn op_empty_variant(&mut self, hash: Hash) -> Result<(), VmError> {
@@ -1546,6 +2119,7 @@ impl Vm {
             match value {
                 Value::Format(format) => {
                     format.spec.format(&format.value, &mut out, &mut buf)?;
+                    buf.clear();
                 }
                 Value::Char(c) => {
                     out.push(c);
@@ -1632,6 +2206,68 @@ impl Vm {
         Ok(())
     }
 
+    /// Internal implementation of the membership test.
+    ///
+    /// Vectors, tuples, objects and strings are handled natively, anything
+    /// else has to implement a `contains` instance function.
+    fn internal_contains(&mut self) -> Result<(), VmError> {
+        let b = self.stack.pop()?;
+        let a = self.stack.pop()?;
+
+        let contains = match (&a, &b) {
+            (a, Value::Vec(vec)) => Self::contains_value(a, vec.borrow_ref()?.iter())?,
+            (a, Value::Tuple(tuple)) => Self::contains_value(a, tuple.borrow_ref()?.iter())?,
+            (Value::String(key), Value::Object(object)) => {
+                object.borrow_ref()?.contains_key(&*key.borrow_ref()?)
+            }
+            (Value::StaticString(key), Value::Object(object)) => {
+                object.borrow_ref()?.contains_key(key.as_str())
+            }
+            (Value::String(needle), Value::String(string)) => {
+                string.borrow_ref()?.contains(&*needle.borrow_ref()?)
+            }
+            (Value::StaticString(needle), Value::String(string)) => {
+                string.borrow_ref()?.contains(needle.as_str())
+            }
+            (Value::String(needle), Value::StaticString(string)) => {
+                string.contains(&*needle.borrow_ref()?)
+            }
+            (Value::StaticString(needle), Value::StaticString(string)) => {
+                string.contains(needle.as_str())
+            }
+            (Value::Char(c), Value::String(string)) => string.borrow_ref()?.contains(*c),
+            (Value::Char(c), Value::StaticString(string)) => string.contains(*c),
+            _ => {
+                if self.call_instance_fn(&b, Hash::of("contains"), (&a,))? {
+                    return Ok(());
+                }
+
+                return Err(VmError::from(VmErrorKind::UnsupportedBinaryOperation {
+                    op: "in",
+                    lhs: a.type_info()?,
+                    rhs: b.type_info()?,
+                }));
+            }
+        };
+
+        self.stack.push(contains);
+        Ok(())
+    }
+
+    /// Test if any of the given values are equal to `value`.
+    fn contains_value<'a>(
+        value: &Value,
+        mut values: impl Iterator<Item = &'a Value>,
+    ) -> Result<bool, VmError> {
+        while let Some(v) = values.next() {
+            if Value::value_ptr_eq(value, v)? {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
     /// Internal implementation of the instance check.
     fn is_instance(&mut self) -> Result<bool, VmError> {
         let b = self.stack.pop()?;
@@ -1935,6 +2571,8 @@ impl Vm {
         let stack = self.stack.drain_stack_top(args)?.collect::<Stack>();
         let mut vm = Self::new_with_stack(self.context.clone(), self.unit.clone(), stack);
         vm.ip = offset;
+        vm.inherit_timer(self);
+        vm.inherit_profile(self);
         self.stack.push(Generator::new(vm));
         Ok(())
     }
@@ -1944,6 +2582,8 @@ impl Vm {
         let stack = self.stack.drain_stack_top(args)?.collect::<Stack>();
         let mut vm = Self::new_with_stack(self.context.clone(), self.unit.clone(), stack);
         vm.ip = offset;
+        vm.inherit_timer(self);
+        vm.inherit_profile(self);
         self.stack.push(Stream::new(vm));
         Ok(())
     }
@@ -1953,6 +2593,8 @@ impl Vm {
         let stack = self.stack.drain_stack_top(args)?.collect::<Stack>();
         let mut vm = Self::new_with_stack(self.context.clone(), self.unit.clone(), stack);
         vm.ip = offset;
+        vm.inherit_timer(self);
+        vm.inherit_profile(self);
         self.stack.push(Future::new(vm.async_complete()));
         Ok(())
     }
@@ -2070,11 +2712,11 @@ impl Vm {
                     call,
                     args: expected,
                 } => {
-                    Self::check_args(args, expected)?;
+                    self.check_fn_args(hash, args, expected)?;
                     self.call_offset_fn(offset, call, args)?;
                 }
                 UnitFn::UnitStruct { hash } => {
-                    Self::check_args(args, 0)?;
+                    self.check_fn_args(hash, args, 0)?;
 
                     let rtti = self
                         .unit
@@ -2087,7 +2729,7 @@ impl Vm {
                     hash,
                     args: expected,
                 } => {
-                    Self::check_args(args, expected)?;
+                    self.check_fn_args(hash, args, expected)?;
                     let tuple = self.stack.pop_sequence(args)?;
 
                     let rtti = self
@@ -2101,7 +2743,7 @@ impl Vm {
                     hash,
                     args: expected,
                 } => {
-                    Self::check_args(args, expected)?;
+                    self.check_fn_args(hash, args, expected)?;
 
                     let rtti = self
                         .unit
@@ -2112,7 +2754,7 @@ impl Vm {
                     self.stack.push(Value::tuple_variant(rtti.clone(), tuple));
                 }
                 UnitFn::UnitVariant { hash } => {
-                    Self::check_args(args, 0)?;
+                    self.check_fn_args(hash, args, 0)?;
 
                     let rtti = self
                         .unit
@@ -2154,7 +2796,7 @@ impl Vm {
                     call,
                     args: expected,
                 } => {
-                    Self::check_args(args, expected)?;
+                    self.check_fn_args(hash, args, expected)?;
                     self.call_offset_fn(offset, call, args)?;
                 }
                 _ => {
@@ -2215,6 +2857,20 @@ impl Vm {
                 return Ok(VmHalt::Limited);
             }
 
+            if let Some(cancellation) = &self.cancellation {
+                if cancellation.is_cancelled() {
+                    return Err(VmError::from(VmErrorKind::Cancelled));
+                }
+            }
+
+            if let Some(limit) = self.instruction_limit {
+                if self.instructions >= limit {
+                    return Err(VmError::from(VmErrorKind::BudgetExceeded { limit }));
+                }
+
+                self.instructions += 1;
+            }
+
             let inst = *self
                 .unit
                 .instruction_at(self.ip)
@@ -2222,6 +2878,18 @@ impl Vm {
 
             log::trace!("{}: {}", self.ip, inst);
 
+            if let Some(TraceHook(hook)) = &self.trace_hook {
+                let frame = self
+                    .stack
+                    .get(self.stack.stack_bottom()..)
+                    .unwrap_or_default();
+                (hook.borrow_mut())(self.ip, &inst, frame);
+            }
+
+            if let Some(Profile(profile)) = &self.profile {
+                *profile.borrow_mut().entry(inst.name()).or_default() += 1;
+            }
+
             match inst {
                 Inst::Not => {
                     self.op_not()?;
@@ -2284,8 +2952,8 @@ impl Vm {
                     // NB: the future itself will advance the virtual machine.
                     return Ok(VmHalt::Awaited(Awaited::Future(future)));
                 }
-                Inst::Select { len } => {
-                    if let Some(select) = self.op_select(len)? {
+                Inst::Select { len, timeout } => {
+                    if let Some(select) = self.op_select(len, timeout)? {
                         // NB: the future itself will advance the virtual machine.
                         return Ok(VmHalt::Awaited(Awaited::Select(select)));
                     }
@@ -2323,6 +2991,9 @@ impl Vm {
                 Inst::Replace { offset } => {
                     self.op_replace(offset)?;
                 }
+                Inst::Swap { a, b } => {
+                    self.stack.swap(a, b)?;
+                }
                 Inst::Jump { offset } => {
                     self.op_jump(offset)?;
                 }
@@ -2347,6 +3018,9 @@ impl Vm {
                 Inst::Tuple { count } => {
                     self.op_tuple(count)?;
                 }
+                Inst::Range { limits } => {
+                    self.op_range(limits)?;
+                }
                 Inst::PushTuple => {
                     self.op_push_tuple()?;
                 }
@@ -2359,6 +3033,9 @@ impl Vm {
                 Inst::Struct { hash, slot } => {
                     self.op_struct(hash, slot)?;
                 }
+                Inst::Spread { hash } => {
+                    self.op_spread(hash)?;
+                }
                 Inst::UnitVariant { hash } => {
                     self.op_empty_variant(hash)?;
                 }
@@ -2434,6 +3111,10 @@ impl Vm {
                 }
             }
 
+            if self.allocations.is_some() && inst.is_allocation() {
+                self.record_allocation()?;
+            }
+
             self.advance();
         }
     }
@@ -2636,19 +3317,101 @@ impl Vm {
         Ok(())
     }
 
-    /// Check that arguments matches expected or raise the appropriate error.
-    fn check_args(args: usize, expected: usize) -> Result<(), VmError> {
-        if args != expected {
-            return Err(VmError::from(VmErrorKind::BadArgumentCount {
+    /// Check the number of arguments to a unit function, naming the function
+    /// in the error if debug information is available.
+    fn check_fn_args(&self, hash: Hash, args: usize, expected: usize) -> Result<(), VmError> {
+        if args == expected {
+            return Ok(());
+        }
+
+        let signature = self
+            .unit
+            .debug_info()
+            .and_then(|debug| debug.functions.get(&hash));
+
+        Err(VmError::from(match signature {
+            Some(signature) => VmErrorKind::BadFunctionArgumentCount {
+                function: signature.path.clone(),
                 actual: args,
                 expected,
-            }));
-        }
+            },
+            None => VmErrorKind::BadArgumentCount {
+                actual: args,
+                expected,
+            },
+        }))
+    }
+}
 
-        Ok(())
+/// A hook called before each instruction is executed.
+///
+/// This is shared by clones of the virtual machine.
+#[derive(Clone)]
+struct TraceHook(Rc<RefCell<dyn FnMut(usize, &Inst, &[Value])>>);
+
+impl fmt::Debug for TraceHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "TraceHook")
     }
 }
 
+/// A host-provided timer which is shared by clones of the virtual machine.
+#[derive(Clone)]
+struct Timer(Rc<dyn Fn(Duration) -> Timeout>);
+
+impl fmt::Debug for Timer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Timer")
+    }
+}
+
+/// Instruction counts which are shared by clones of the virtual machine.
+#[derive(Default, Clone)]
+struct Profile(Rc<RefCell<HashMap<&'static str, usize>>>);
+
+impl fmt::Debug for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Profile")
+    }
+}
+
+/// Allocation sites of values which are shared by clones of the virtual
+/// machine.
+#[derive(Default, Clone)]
+struct Allocations(Rc<RefCell<vec::Vec<(LiveSlot, Box<dyn IsAlive>)>>>);
+
+impl fmt::Debug for Allocations {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Allocations")
+    }
+}
+
+/// Helper trait to test if a weak reference of any type is still alive.
+trait IsAlive {
+    fn is_alive(&self) -> bool;
+}
+
+impl<T: ?Sized> IsAlive for Weak<T> {
+    fn is_alive(&self) -> bool {
+        Weak::is_alive(self)
+    }
+}
+
+/// A value which is still alive, as reported by [Vm::live_slots].
+#[derive(Debug, Clone)]
+pub struct LiveSlot {
+    /// The instruction pointer of the instruction that allocated the value.
+    pub ip: usize,
+    /// The source the allocating instruction belongs to, if the unit has
+    /// debug information.
+    pub source_id: Option<usize>,
+    /// The span of the allocating instruction, if the unit has debug
+    /// information.
+    pub span: Option<Span>,
+    /// Type information of the allocated value.
+    pub type_info: TypeInfo,
+}
+
 /// A call frame.
 ///
 /// This is used to store the return point after an instruction has been run.
@@ -14,7 +14,16 @@ impl VmCall {
     }
 
     /// Encode the push itno an execution.
-    pub(crate) fn into_execution(self, execution: &mut VmExecution) -> Result<(), VmError> {
+    pub(crate) fn into_execution(mut self, execution: &mut VmExecution) -> Result<(), VmError> {
+        // NB: the nested virtual machine is cancelled, traced, tracks
+        // allocations, is profiled, and times out together with the one
+        // calling it.
+        self.vm.inherit_cancellation(execution.vm()?);
+        self.vm.inherit_trace_hook(execution.vm()?);
+        self.vm.inherit_allocations(execution.vm()?);
+        self.vm.inherit_profile(execution.vm()?);
+        self.vm.inherit_timer(execution.vm()?);
+
         let value = match self.call {
             Call::Async => Value::from(Future::new(self.vm.async_complete())),
             Call::Stream => Value::from(Stream::new(self.vm)),
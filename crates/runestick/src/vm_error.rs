@@ -1,6 +1,7 @@
 use crate::panic::BoxedPanic;
 use crate::{
-    AccessError, Hash, Item, Panic, Protocol, StackError, TypeInfo, TypeOf, Unit, Value, VmHaltInfo,
+    AccessError, Hash, Item, Panic, Protocol, Range, Span, StackError, TypeInfo, TypeOf, Unit,
+    Value, VmHaltInfo,
 };
 use std::fmt;
 use std::sync::Arc;
@@ -62,8 +63,39 @@ impl VmError {
         *self.kind
     }
 
+    /// Indicate that the error happened while converting the value at the
+    /// given `segment`, like an index in a vector being converted.
+    ///
+    /// Segments are prepended, so nested conversions build up the full path
+    /// to the value which failed to convert as the error propagates.
+    pub fn with_path(self, segment: ValuePathSegment) -> Self {
+        match *self.kind {
+            VmErrorKind::ValuePath { mut path, kind } => {
+                path.segments.insert(0, segment);
+                Self::from(VmErrorKind::ValuePath { path, kind })
+            }
+            kind => Self::from(VmErrorKind::ValuePath {
+                path: ValuePath {
+                    segments: vec![segment],
+                },
+                kind: Box::new(kind),
+            }),
+        }
+    }
+
     /// Convert into an unwinded vm error.
     pub fn into_unwinded(self, unit: &Arc<Unit>, ip: usize) -> Self {
+        self.into_unwinded_with_frames(unit, ip, Vec::new())
+    }
+
+    /// Convert into an unwinded vm error, which also records the instruction
+    /// pointers of the calls which led up to the error, innermost first.
+    pub fn into_unwinded_with_frames(
+        self,
+        unit: &Arc<Unit>,
+        ip: usize,
+        frames: Vec<usize>,
+    ) -> Self {
         if let VmErrorKind::Unwound { .. } = &*self.kind {
             return self;
         }
@@ -72,21 +104,54 @@ impl VmError {
             kind: self.kind,
             unit: unit.clone(),
             ip,
+            frames,
         })
     }
 
     /// Unpack an unwinded error, if it is present.
     pub fn as_unwound<'a>(&'a self) -> (&'a VmErrorKind, Option<(&'a Arc<Unit>, usize)>) {
         match &*self.kind {
-            VmErrorKind::Unwound { kind, unit, ip } => (&*kind, Some((unit, *ip))),
+            VmErrorKind::Unwound { kind, unit, ip, .. } => (&*kind, Some((unit, *ip))),
             kind => (kind, None),
         }
     }
 
+    /// Get the backtrace of an unwound error, starting with the frame where
+    /// the error happened followed by the calls which led up to it.
+    ///
+    /// Items and spans are only available if the unit has debug info. Errors
+    /// which haven't been unwound have an empty backtrace.
+    pub fn backtrace(&self) -> Vec<BacktraceFrame> {
+        let (unit, ip, frames) = match &*self.kind {
+            VmErrorKind::Unwound {
+                unit, ip, frames, ..
+            } => (unit, *ip, frames),
+            _ => return Vec::new(),
+        };
+
+        let debug = unit.debug_info();
+
+        std::iter::once(ip)
+            .chain(frames.iter().copied())
+            .map(|ip| {
+                let inst = debug.and_then(|d| d.instruction_at(ip));
+
+                BacktraceFrame {
+                    ip,
+                    item: debug
+                        .and_then(|d| d.function_containing(ip))
+                        .map(|(_, signature)| signature.path.clone()),
+                    source_id: inst.map(|inst| inst.source_id),
+                    span: inst.map(|inst| inst.span),
+                }
+            })
+            .collect()
+    }
+
     /// Unpack an unwinded error, if it is present.
     pub fn into_unwound(self) -> (Self, Option<(Arc<Unit>, usize)>) {
         match *self.kind {
-            VmErrorKind::Unwound { kind, unit, ip } => {
+            VmErrorKind::Unwound { kind, unit, ip, .. } => {
                 let error = Self { kind };
                 (error, Some((unit, ip)))
             }
@@ -143,6 +208,9 @@ pub enum VmErrorKind {
         unit: Arc<Unit>,
         /// The instruction pointer of where the original error happened.
         ip: usize,
+        /// The instruction pointers of the calls which led up to the error,
+        /// innermost first.
+        frames: Vec<usize>,
     },
     #[error("{error}")]
     AccessError {
@@ -153,6 +221,21 @@ pub enum VmErrorKind {
     Panic { reason: Panic },
     #[error("no running virtual machines")]
     NoRunningVm,
+    #[error("execution was cancelled")]
+    Cancelled,
+    #[error("instruction limit of {limit} exceeded")]
+    BudgetExceeded { limit: usize },
+    #[error("stack overflow, maximum call depth of {depth} exceeded")]
+    StackOverflow { depth: usize },
+    /// An error raised while converting a nested value, like an element in a
+    /// vector.
+    #[error("at {path}: {kind}")]
+    ValuePath {
+        /// The path to the value which failed to convert.
+        path: ValuePath,
+        /// The wrapped error.
+        kind: Box<VmErrorKind>,
+    },
     #[error("halted for unexpected reason `{halt}`")]
     Halted { halt: VmHaltInfo },
     #[error("failed to format argument")]
@@ -180,6 +263,8 @@ pub enum VmErrorKind {
     IpOutOfBounds,
     #[error("unsupported target for .await `{actual}`")]
     UnsupportedAwait { actual: TypeInfo },
+    #[error("cannot time out select since no timer is configured")]
+    MissingTimer,
     #[error("unsupported vm operation `{lhs} {op} {rhs}`")]
     UnsupportedBinaryOperation {
         op: &'static str,
@@ -203,6 +288,12 @@ pub enum VmErrorKind {
     MissingRtti { hash: Hash },
     #[error("wrong number of arguments `{actual}`, expected `{expected}`")]
     BadArgumentCount { actual: usize, expected: usize },
+    #[error("wrong number of arguments `{actual}` to `{function}`, expected `{expected}`")]
+    BadFunctionArgumentCount {
+        function: Item,
+        actual: usize,
+        expected: usize,
+    },
     #[error("bad argument #{arg}, expected `{expected}` but got `{actual}`")]
     BadArgumentType {
         arg: usize,
@@ -228,6 +319,10 @@ pub enum VmErrorKind {
         index: TypeInfo,
         value: TypeInfo,
     },
+    #[error("`{type_info}` can't be modified by assigning to an index")]
+    NotIndexAssignable { type_info: TypeInfo },
+    #[error("`{actual}` can't be spread into `{target}`")]
+    UnsupportedSpread { target: TypeInfo, actual: TypeInfo },
     #[error("the index get operation `{target}[{index}]` is not supported")]
     UnsupportedIndexGet { target: TypeInfo, index: TypeInfo },
     #[error("the tuple index get operation is not supported on `{target}`")]
@@ -250,6 +345,14 @@ pub enum VmErrorKind {
         target: TypeInfo,
         index: VmIntegerRepr,
     },
+    #[error("range `{range}` is out of bounds for `{target}` of length {len}")]
+    SliceOutOfBounds {
+        target: TypeInfo,
+        range: Range,
+        len: usize,
+    },
+    #[error("range `{range}` used to slice `{target}` starts after it ends")]
+    SliceReversed { target: TypeInfo, range: Range },
     #[error("missing field `{field}` on `{target}`")]
     MissingField { target: TypeInfo, field: String },
     #[error("missing dynamic field for struct field `{target}::{name}`")]
@@ -282,11 +385,15 @@ pub enum VmErrorKind {
         from: VmIntegerRepr,
         to: &'static str,
     },
+    #[error("failed to convert value `{from}` to a character, not a valid code point")]
+    ValueToCharCoercionError { from: VmIntegerRepr },
     #[error("failed to convert integer `{from}` to value `{to}`")]
     IntegerToValueCoercionError {
         from: VmIntegerRepr,
         to: &'static str,
     },
+    #[error("expected a {expected}-tuple, but found `{actual}`")]
+    ExpectedTuple { actual: TypeInfo, expected: usize },
     #[error("expected a tuple of length `{expected}`, but found one with length `{actual}`")]
     ExpectedTupleLength { actual: usize, expected: usize },
     #[error("unexpectedly ran out of items to iterate over")]
@@ -305,12 +412,75 @@ impl VmErrorKind {
     /// Unpack an unwound error, if it is present.
     pub fn as_unwound_ref(&self) -> (&Self, Option<(Arc<Unit>, usize)>) {
         match self {
-            VmErrorKind::Unwound { kind, unit, ip } => (&*kind, Some((unit.clone(), *ip))),
+            VmErrorKind::Unwound { kind, unit, ip, .. } => (&*kind, Some((unit.clone(), *ip))),
             kind => (kind, None),
         }
     }
 }
 
+/// A single frame in the backtrace of a [VmError].
+#[derive(Debug, Clone)]
+pub struct BacktraceFrame {
+    /// The instruction pointer of the frame.
+    pub ip: usize,
+    /// The function the instruction belongs to.
+    pub item: Option<Item>,
+    /// The source the instruction was compiled from.
+    pub source_id: Option<usize>,
+    /// The span of the instruction.
+    pub span: Option<Span>,
+}
+
+/// The path to a nested value, used to report where a conversion failed.
+#[derive(Debug, Clone, Default)]
+pub struct ValuePath {
+    segments: Vec<ValuePathSegment>,
+}
+
+impl ValuePath {
+    /// Access the segments of the path, from the outermost value inwards.
+    pub fn segments(&self) -> &[ValuePathSegment] {
+        &self.segments
+    }
+}
+
+impl fmt::Display for ValuePath {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut it = self.segments.iter();
+
+        if let Some(segment) = it.next() {
+            write!(fmt, "{}", segment)?;
+        }
+
+        for segment in it {
+            write!(fmt, ", {}", segment)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A single segment in a [ValuePath].
+#[derive(Debug, Clone)]
+pub enum ValuePathSegment {
+    /// An index in a vector.
+    Index(usize),
+    /// A field in a tuple.
+    TupleField(usize),
+    /// A key in an object.
+    Key(String),
+}
+
+impl fmt::Display for ValuePathSegment {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Index(index) => write!(fmt, "index {}", index),
+            Self::TupleField(index) => write!(fmt, "field {}", index),
+            Self::Key(key) => write!(fmt, "key `{}`", key),
+        }
+    }
+}
+
 /// A type-erased rust number.
 #[derive(Debug, Clone, Copy)]
 pub enum VmIntegerRepr {
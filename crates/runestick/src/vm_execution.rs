@@ -211,7 +211,10 @@ impl VmExecution {
     fn run(vm: &mut Vm) -> Result<VmHalt, VmError> {
         match vm.run() {
             Ok(reason) => Ok(reason),
-            Err(error) => Err(error.into_unwinded(vm.unit(), vm.ip())),
+            Err(error) => {
+                let frames = vm.call_frames().iter().rev().map(|f| f.ip()).collect();
+                Err(error.into_unwinded_with_frames(vm.unit(), vm.ip(), frames))
+            }
         }
     }
 }